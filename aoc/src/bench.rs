@@ -0,0 +1,258 @@
+use aoc_common::{ParseResult, Parsed};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::days::Day;
+use crate::mem::{self, Usage};
+use crate::output::{format_bytes, format_ns};
+use crate::table;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Summary of one phase of one day over every iteration of a run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub phase: Phase,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Run {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub iterations: usize,
+    pub timings: Vec<Timing>,
+}
+
+/// Every saved bench run, oldest first.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+impl History {
+    pub fn default_path() -> PathBuf {
+        crate::root().join("bench.json")
+    }
+
+    /// Loads the history at `path`, or an empty one if nothing has been saved yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                serde_json::from_str(&text).map_err(|err| format!("{}: {}", path.display(), err))
+            }
+            Err(_) if !path.exists() => Ok(History::default()),
+            Err(err) => Err(format!("couldn't read {}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, text + "\n")
+            .map_err(|err| format!("couldn't write {}: {}", path.display(), err))
+    }
+
+    /// Most recent saved timing for this day and phase.
+    pub fn previous(&self, day: u8, phase: Phase) -> Option<&Timing> {
+        self.runs.iter().rev().find_map(|run| {
+            run.timings
+                .iter()
+                .find(|timing| timing.day == day && timing.phase == phase)
+        })
+    }
+
+    pub fn push(&mut self, iterations: usize, timings: Vec<Timing>) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        self.runs.push(Run {
+            timestamp,
+            iterations,
+            timings,
+        });
+    }
+}
+
+fn summarise(day: u8, phase: Phase, samples: &mut [Duration]) -> Timing {
+    samples.sort();
+    let nanos = |duration: &Duration| duration.as_nanos() as u64;
+    Timing {
+        day,
+        phase,
+        median_ns: nanos(&samples[samples.len() / 2]),
+        min_ns: nanos(&samples[0]),
+        max_ns: nanos(&samples[samples.len() - 1]),
+    }
+}
+
+/// Rows for the phases of one day that ran, and an error for each part that
+/// failed or panicked, which has no row.
+#[derive(Debug)]
+pub struct Benched<T> {
+    pub rows: Vec<T>,
+    pub failed: Vec<String>,
+}
+
+/// Runs `part`, with a failure or a panic as the error to print.
+fn run_part(day: &Day, parsed: &dyn Parsed, part: u8) -> Result<(), String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| black_box(parsed.part(part)))) {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(err)) => Err(format!(
+            "error: day {} part {} failed on input: {}\n",
+            day.number, part, err
+        )),
+        Err(_) => Err(format!(
+            "error: day {} part {} panicked on input\n",
+            day.number, part
+        )),
+    }
+}
+
+/// Times parse, part 1 and part 2 of `day` separately over `iterations` runs. A
+/// part that fails isn't run again.
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> ParseResult<Benched<Timing>> {
+    let mut parse = Vec::new();
+    let mut parts = [(1, Vec::new(), None), (2, Vec::new(), None)];
+
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let parsed = day.solution.parse(black_box(input))?;
+        parse.push(start.elapsed());
+
+        for (part, samples, failed) in &mut parts {
+            if failed.is_some() {
+                continue;
+            }
+            let start = Instant::now();
+            match run_part(day, &*parsed, *part) {
+                Ok(()) => samples.push(start.elapsed()),
+                Err(err) => *failed = Some(err),
+            }
+        }
+    }
+
+    let mut benched = Benched {
+        rows: vec![summarise(day.number, Phase::Parse, &mut parse)],
+        failed: Vec::new(),
+    };
+    for ((_, mut samples, failed), phase) in parts.into_iter().zip([Phase::Part1, Phase::Part2]) {
+        match failed {
+            Some(err) => benched.failed.push(err),
+            None => benched
+                .rows
+                .push(summarise(day.number, phase, &mut samples)),
+        }
+    }
+    Ok(benched)
+}
+
+/// Table of `timings` next to the previous run's medians. Any median more than
+/// `threshold` percent slower than before is flagged.
+pub fn report(timings: &[Timing], history: &History, threshold: f64) -> String {
+    let rows: Vec<Vec<String>> = timings
+        .iter()
+        .map(|timing| {
+            let mut row = vec![
+                timing.day.to_string(),
+                timing.phase.to_string(),
+                format_ns(timing.median_ns),
+                format_ns(timing.min_ns),
+                format_ns(timing.max_ns),
+            ];
+            if let Some(prev) = history.previous(timing.day, timing.phase) {
+                let change = (timing.median_ns as f64 - prev.median_ns as f64)
+                    / (prev.median_ns.max(1) as f64)
+                    * 100.0;
+                row.push(format_ns(prev.median_ns));
+                row.push(format!("{:+.1}%", change));
+                if change > threshold {
+                    row.push("REGRESSION".to_string());
+                }
+            }
+            row
+        })
+        .collect();
+
+    table::format(
+        &[
+            "day", "phase", "median", "min", "max", "previous", "change", "",
+        ],
+        &rows,
+    )
+}
+
+/// What one phase of one day allocated.
+#[derive(Debug, Clone)]
+pub struct Memory {
+    pub day: u8,
+    pub phase: Phase,
+    pub usage: Usage,
+}
+
+/// Counts what parse, part 1 and part 2 of `day` allocate. The parsed input is
+/// still live while the parts run, so isn't counted in their peaks.
+pub fn mem_day(day: &Day, input: &str) -> ParseResult<Benched<Memory>> {
+    let (parsed, usage) = mem::measure(|| day.solution.parse(input));
+    let parsed = parsed?;
+    let memory = |phase, usage| Memory {
+        day: day.number,
+        phase,
+        usage,
+    };
+
+    let mut benched = Benched {
+        rows: vec![memory(Phase::Parse, usage)],
+        failed: Vec::new(),
+    };
+    for (part, phase) in [(1, Phase::Part1), (2, Phase::Part2)] {
+        match mem::measure(|| run_part(day, &*parsed, part)) {
+            (Ok(()), usage) => benched.rows.push(memory(phase, usage)),
+            (Err(err), _) => benched.failed.push(err),
+        }
+    }
+    Ok(benched)
+}
+
+pub fn mem_report(memory: &[Memory]) -> String {
+    let rows: Vec<Vec<String>> = memory
+        .iter()
+        .map(|memory| {
+            vec![
+                memory.day.to_string(),
+                memory.phase.to_string(),
+                memory.usage.allocations.to_string(),
+                format_bytes(memory.usage.bytes),
+                format_bytes(memory.usage.peak_heap),
+            ]
+        })
+        .collect();
+    table::format(
+        &["day", "phase", "allocations", "bytes", "peak heap"],
+        &rows,
+    )
+}
@@ -0,0 +1,170 @@
+//! Talking to the Advent of Code site, for `aoc fetch` and `aoc submit`. Every request carries
+//! the session cookie and waits out a minimum interval since the last one.
+
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const USER_AGENT: &str = "github.com/jj-tetraquark/advent-of-code-2023 aoc-runner";
+
+/// Settings from `aoc.toml` in the workspace root, which isn't checked in as it
+/// holds the session token. The environment overrides the file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The `session` cookie from a logged in browser
+    pub session: Option<String>,
+    pub base_url: String,
+    /// Least time between two requests, across runs
+    pub interval_ms: u64,
+    /// Where the time of the last request is kept, so separate runs respect the
+    /// interval too. `None` only limits requests within one run
+    pub stamp: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            interval_ms: 5000,
+            stamp: Some(crate::root().join("target").join("aoc-last-request")),
+        }
+    }
+}
+
+impl Config {
+    pub fn default_path() -> PathBuf {
+        crate::root().join("aoc.toml")
+    }
+
+    /// Reads `path` if it exists, then applies `AOC_SESSION` and `AOC_BASE_URL`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(text) => {
+                toml::from_str(&text).map_err(|err| format!("{}: {}", path.display(), err))?
+            }
+            Err(_) if !path.exists() => Config::default(),
+            Err(err) => return Err(format!("couldn't read {}: {}", path.display(), err)),
+        };
+        if let Ok(session) = std::env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var(BASE_URL_VAR) {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+    /// When this client last made a request, in milliseconds since the epoch
+    last: Option<u64>,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        Client {
+            config,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            last: None,
+        }
+    }
+
+    fn session(&self) -> Result<&str, String> {
+        match self.config.session.as_deref() {
+            Some(session) if !session.trim().is_empty() => Ok(session.trim()),
+            _ => Err(format!(
+                "no session token, set {} or `session` in {}",
+                SESSION_VAR,
+                Config::default_path().display()
+            )),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.config.base_url.trim_end_matches('/'), path)
+    }
+
+    /// Sleeps until the interval since the last request, from this run or the
+    /// stamp file, has passed, then records a new request.
+    fn wait_turn(&mut self) {
+        let stamped = self
+            .config
+            .stamp
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|text| text.trim().parse().ok());
+        if let Some(last) = self.last.max(stamped) {
+            let ready = last + self.config.interval_ms;
+            let now = now_ms();
+            if ready > now {
+                thread::sleep(Duration::from_millis(ready - now));
+            }
+        }
+
+        let now = now_ms();
+        self.last = Some(now);
+        if let Some(path) = &self.config.stamp {
+            // Losing the stamp only loosens the limit for the next run
+            let _ = fs::write(path, now.to_string());
+        }
+    }
+
+    fn send(
+        &mut self,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, String> {
+        let session = self.session()?.to_string();
+        self.wait_turn();
+
+        let request = request.set("Cookie", &format!("session={}", session));
+        let url = request.url().to_string();
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| format!("couldn't read the response from {}: {}", url, err)),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                let reason = match code {
+                    400 | 401 => "the session token was rejected",
+                    404 => "not found, is the puzzle unlocked yet?",
+                    _ => body.trim(),
+                };
+                Err(format!("{} returned {}: {}", url, code, reason))
+            }
+            Err(err) => Err(format!("couldn't reach {}: {}", url, err)),
+        }
+    }
+
+    /// GETs `path`, relative to the base URL.
+    pub fn get(&mut self, path: &str) -> Result<String, String> {
+        let request = self.agent.get(&self.url(path));
+        self.send(request, None)
+    }
+
+    /// POSTs `form` to `path`, relative to the base URL.
+    pub fn post(&mut self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let request = self.agent.post(&self.url(path));
+        self.send(request, Some(form))
+    }
+}
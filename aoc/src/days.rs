@@ -0,0 +1,61 @@
+use aoc_common::DynSolution;
+use std::fs;
+use std::path::PathBuf;
+
+pub struct Day {
+    pub number: u8,
+    pub solution: &'static dyn DynSolution,
+}
+
+impl Day {
+    pub fn dir(&self) -> PathBuf {
+        crate::root().join(format!("day{}", self.number))
+    }
+
+    pub fn default_input(&self) -> PathBuf {
+        self.dir().join("input")
+    }
+
+    /// The worked examples checked in next to the input, named `test*`.
+    pub fn example_inputs(&self) -> Vec<PathBuf> {
+        let mut examples: Vec<PathBuf> = fs::read_dir(self.dir())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .file_name()
+                        .is_some_and(|name| name.to_string_lossy().starts_with("test"))
+            })
+            .collect();
+        examples.sort();
+        examples
+    }
+}
+
+macro_rules! day {
+    ($number:expr, $solution:expr) => {
+        Day {
+            number: $number,
+            solution: &$solution,
+        }
+    };
+}
+
+pub const DAYS: [Day; 9] = [
+    day!(1, day1::Day1::DEFAULT),
+    day!(2, day2::Day2::DEFAULT),
+    day!(3, day3::Day3::DEFAULT),
+    day!(4, day4::Day4),
+    day!(5, day5::Day5),
+    day!(6, day6::Day6),
+    day!(7, day7::Day7),
+    day!(8, day8::Day8::DEFAULT),
+    day!(9, day9::Day9),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
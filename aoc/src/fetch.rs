@@ -0,0 +1,34 @@
+//! `aoc fetch`: downloads puzzle inputs into the day directories, once.
+
+use std::fs;
+use std::path::Path;
+
+use crate::client::Client;
+
+pub const YEAR: u16 = 2023;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// Already there, so nothing was requested
+    Cached,
+    /// Downloaded this many bytes
+    Downloaded(usize),
+}
+
+/// Downloads `day`'s input to `path` unless something is already there.
+pub fn fetch_input(client: &mut Client, day: u8, path: &Path) -> Result<Fetched, String> {
+    if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client.get(&format!("/{}/day/{}/input", YEAR, day))?;
+    if input.trim().is_empty() {
+        return Err(format!("day {}: the downloaded input is empty", day));
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("couldn't create {}: {}", dir.display(), err))?;
+    }
+    fs::write(path, &input).map_err(|err| format!("couldn't write {}: {}", path.display(), err))?;
+    Ok(Fetched::Downloaded(input.len()))
+}
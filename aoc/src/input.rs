@@ -0,0 +1,70 @@
+use aoc_common::input::{self, Irregularity, Normalizer};
+use std::fs;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Input path meaning "read from stdin".
+pub const STDIN: &str = "-";
+
+pub fn is_stdin(path: &Path) -> bool {
+    path.as_os_str() == STDIN
+}
+
+/// Name to show for `path` in output and diagnostics.
+pub fn display_name(path: &Path) -> String {
+    if is_stdin(path) {
+        "<stdin>".to_string()
+    } else {
+        path.display().to_string()
+    }
+}
+
+/// Replaces every directory in `paths` with the files directly inside it, in name order.
+pub fn expand(paths: &[PathBuf]) -> io::Result<Vec<PathBuf>> {
+    let mut expanded = Vec::new();
+    for path in paths {
+        if !is_stdin(path) && path.is_dir() {
+            let mut files: Vec<PathBuf> = fs::read_dir(path)?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<io::Result<_>>()?;
+            files.retain(|file| file.is_file());
+            files.sort();
+            expanded.extend(files);
+        } else {
+            expanded.push(path.clone());
+        }
+    }
+    Ok(expanded)
+}
+
+/// Prints a warning for each thing tidied in `path`'s input.
+pub fn warn(path: &Path, irregularities: &[Irregularity]) {
+    for irregularity in irregularities {
+        eprintln!("warning: {}: {}", display_name(path), irregularity);
+    }
+}
+
+/// Reads and normalises the input at `path`, warning about anything tidied.
+pub fn read(path: &Path) -> io::Result<String> {
+    let raw = if is_stdin(path) {
+        let mut raw = String::new();
+        io::stdin().read_to_string(&mut raw)?;
+        raw
+    } else {
+        fs::read_to_string(path)?
+    };
+    let normalized = input::normalize(&raw);
+    warn(path, &normalized.irregularities);
+    Ok(normalized.text)
+}
+
+/// Opens `path` to be normalised a line at a time, without loading it.
+pub fn open(path: &Path) -> io::Result<Normalizer<Box<dyn BufRead>>> {
+    let reader: Box<dyn BufRead> = if is_stdin(path) {
+        Box::new(io::stdin().lock())
+    } else {
+        Box::new(BufReader::new(File::open(path)?))
+    };
+    Ok(Normalizer::new(reader))
+}
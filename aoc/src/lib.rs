@@ -0,0 +1,26 @@
+use std::path::{Path, PathBuf};
+
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod fetch;
+pub mod input;
+pub mod manifest;
+pub mod mem;
+pub mod output;
+pub mod params;
+pub mod pool;
+pub mod repl;
+pub mod run;
+pub mod scaffold;
+pub mod submit;
+pub mod table;
+pub mod watch;
+
+/// Root of the workspace, where the day directories and `answers.toml` live.
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
@@ -0,0 +1,769 @@
+use aoc_common::Width;
+use clap::{Args, Parser, Subcommand};
+use std::fs::File;
+use std::io::{self, BufWriter, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::bench::{self, History};
+use aoc::client::{Client, Config};
+use aoc::days::{self, Day};
+use aoc::fetch::{self, Fetched};
+use aoc::input;
+use aoc::manifest;
+use aoc::mem;
+use aoc::output::{format_bytes, format_ns, Format};
+use aoc::params::Overrides;
+use aoc::pool;
+use aoc::repl::Repl;
+use aoc::run::{self, Job, Options};
+use aoc::scaffold;
+use aoc::submit::{self, Log};
+use aoc::table;
+use aoc::watch::Watcher;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day's solution, or every day with --all
+    Run(RunArgs),
+    /// Time parse, part 1 and part 2 of each day and compare with the last bench run
+    Bench(BenchArgs),
+    /// Write a random but valid input for a day, at any size
+    Gen(GenArgs),
+    /// List the puzzle parameters each day can be run with, and their defaults
+    Params(ParamsArgs),
+    /// Rerun a day whenever its input or examples change
+    Watch(WatchArgs),
+    /// Download a day's puzzle input into its directory, unless it's already there
+    Fetch(FetchArgs),
+    /// Run one part of a day and send its answer, unless the attempt log rules it out
+    Submit(SubmitArgs),
+    /// Start a crate for a new day and register it with the runner
+    New(NewArgs),
+    /// Parse a day's input once, then answer questions about it from stdin
+    Repl(ReplArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Only run this part (1 or 2)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run every day against its checked in input, in parallel
+    #[arg(long)]
+    all: bool,
+
+    /// With --all, also run each day's example inputs
+    #[arg(long, requires = "all")]
+    examples: bool,
+
+    /// With --all, how many inputs to run at once. Defaults to the number of CPUs
+    #[arg(
+        long,
+        requires = "all",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    jobs: Option<usize>,
+
+    /// Puzzle inputs: files, directories of files, or `-` for stdin. Defaults to the
+    /// day's `input` file
+    #[arg(conflicts_with = "all")]
+    inputs: Vec<PathBuf>,
+
+    /// How to print each result
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Show the intermediate state behind each answer, for days that have any
+    #[arg(long, conflicts_with = "all")]
+    explain: bool,
+
+    /// Read the input a line at a time rather than loading it all first, for
+    /// inputs too big to hold in memory. Only some days can
+    #[arg(long, conflicts_with_all = ["all", "explain"])]
+    stream: bool,
+
+    /// Integer type to work answers out in: native, u64, u128 or big. Days with
+    /// signed answers use i64 and i128 for u64 and u128. Numbers in the input are
+    /// still read as the day's usual type
+    #[arg(long, default_value_t = Width::Native)]
+    width: Width,
+
+    /// Set a puzzle parameter, as `name=value` for the day being run or
+    /// `dayN.name=value`. `aoc params` lists them
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    params: Vec<String>,
+
+    /// TOML file of puzzle parameters, with a `[dayN]` table for each day.
+    /// --param values override it
+    #[arg(long, value_name = "FILE")]
+    param_file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only bench this day
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Times to run each phase
+    #[arg(long, default_value_t = 10)]
+    iterations: usize,
+
+    /// Flag medians that got slower than the last run by more than this percentage
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Bench history file, defaults to `bench.json` in the workspace root
+    #[arg(long)]
+    history: Option<PathBuf>,
+
+    /// Count allocations, bytes allocated and peak live heap bytes for each
+    /// phase instead of timing them. Only heap allocations are seen, not the
+    /// stack or mapped files. Needs a build with `--features mem`
+    #[arg(long)]
+    mem: bool,
+}
+
+/// Prints `message` unless it's the same as the last one, since every part of an
+/// input that fails to parse fails the same way.
+fn print_error(message: String, last: &mut Option<String>) {
+    if last.as_ref() != Some(&message) {
+        eprint!("{}", message);
+    }
+    *last = Some(message);
+}
+
+fn run_day(
+    day: &'static Day,
+    parts: &[u8],
+    fname: &Path,
+    format: Format,
+    options: &Options,
+) -> bool {
+    let name = input::display_name(fname);
+    if options.stream {
+        return stream_day(day, parts, fname, &name, format, options);
+    }
+    let input = match input::read(fname) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: couldn't read {}: {}", name, err);
+            return false;
+        }
+    };
+
+    let job = Job { day, name, input };
+    let mut ok = true;
+    let mut last_error = None;
+    for result in run::run_parts(&job, parts, options) {
+        match result {
+            Ok(record) => println!("{}", record.format(format)),
+            Err(message) => {
+                print_error(message, &mut last_error);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+fn stream_day(
+    day: &'static Day,
+    parts: &[u8],
+    fname: &Path,
+    name: &str,
+    format: Format,
+    options: &Options,
+) -> bool {
+    let mut reader = match input::open(fname) {
+        Ok(reader) => reader,
+        Err(err) => {
+            eprintln!("error: couldn't read {}: {}", name, err);
+            return false;
+        }
+    };
+
+    let results = run::stream_parts(day, name, &mut reader, parts, options);
+    input::warn(fname, &reader.irregularities());
+
+    let mut ok = true;
+    let mut last_error = None;
+    for result in results {
+        match result {
+            Ok(record) => println!("{}", record.format(format)),
+            Err(message) => {
+                print_error(message, &mut last_error);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+fn run_all(parts: &[u8], examples: bool, jobs: usize, format: Format, options: &Options) -> bool {
+    let mut ok = true;
+    let mut loaded = Vec::new();
+    for day in days::DAYS.iter() {
+        let mut fnames = vec![day.default_input()];
+        if examples {
+            fnames.extend(day.example_inputs());
+        }
+
+        for fname in fnames {
+            let root = aoc::root();
+            let name = fname.strip_prefix(&root).unwrap_or(&fname);
+            let name = name.display().to_string();
+            match input::read(&fname) {
+                Ok(input) => loaded.push(Job { day, name, input }),
+                Err(err) => {
+                    eprintln!("error: couldn't read {}: {}", name, err);
+                    ok = false;
+                }
+            }
+        }
+    }
+
+    // One task per input, so each is parsed once for all of its parts
+    let start = Instant::now();
+    let results = pool::map(&loaded, jobs, |job| run::run_parts(job, parts, options));
+    let wall = start.elapsed();
+
+    let mut rows = Vec::new();
+    let mut last_error = None;
+    for result in results.into_iter().flatten() {
+        match result {
+            Ok(record) if format == Format::Text => rows.push(vec![
+                record.day.to_string(),
+                record.part.to_string(),
+                record.input,
+                record.answer,
+                format_ns(record.elapsed_ns),
+            ]),
+            Ok(record) => println!("{}", record.format(format)),
+            Err(message) => {
+                print_error(message, &mut last_error);
+                ok = false;
+            }
+        }
+    }
+
+    if format == Format::Text {
+        print!(
+            "{}",
+            table::format(&["day", "part", "input", "answer", "time"], &rows)
+        );
+        println!(
+            "{} inputs on {} threads in {:.1?}",
+            loaded.len(),
+            jobs,
+            wall
+        );
+    }
+    ok
+}
+
+/// The parameter file, then each `--param`, checked against the days they're for.
+fn overrides(args: &RunArgs) -> Result<Overrides, String> {
+    let mut overrides = match &args.param_file {
+        Some(path) => Overrides::load(path)?,
+        None => Overrides::default(),
+    };
+    for arg in &args.params {
+        overrides.add_arg(arg, args.day)?;
+    }
+    overrides.check()?;
+    Ok(overrides)
+}
+
+fn run(args: RunArgs) {
+    let parts: Vec<u8> = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let params = overrides(&args).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+    let options = Options {
+        explain: args.explain,
+        width: args.width,
+        params,
+        stream: args.stream,
+    };
+    let ok = if args.all {
+        let jobs = args.jobs.unwrap_or_else(pool::default_jobs);
+        run_all(&parts, args.examples, jobs, args.format, &options)
+    } else {
+        let number = args.day.unwrap();
+        let Some(day) = days::get(number) else {
+            eprintln!("error: no solution for day {}", number);
+            process::exit(1);
+        };
+        let fnames = if args.inputs.is_empty() {
+            vec![day.default_input()]
+        } else {
+            input::expand(&args.inputs).unwrap_or_else(|err| {
+                eprintln!("error: {}", err);
+                process::exit(1);
+            })
+        };
+
+        let mut ok = true;
+        for fname in &fnames {
+            if fnames.len() > 1 && args.format == Format::Text {
+                println!("==> {} <==", input::display_name(fname));
+            }
+            ok = run_day(day, &parts, fname, args.format, &options) && ok;
+        }
+        ok
+    };
+
+    if !ok {
+        process::exit(1);
+    }
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day to generate an input for
+    #[arg(long)]
+    day: u8,
+
+    /// How many lines, games, cards, hands, sequences or races to write. For day 3
+    /// the side of the schematic, for day 5 the mappings in each map and for day 8
+    /// the nodes between AAA and ZZZ
+    #[arg(long)]
+    size: usize,
+
+    /// The same seed always gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// File to write to, defaults to stdout
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+fn gen(args: GenArgs) {
+    let result = match &args.output {
+        Some(path) => File::create(path).and_then(|file| {
+            let mut out = BufWriter::new(file);
+            aoc_gen::write(args.day, args.size, args.seed, &mut out)?;
+            out.flush()
+        }),
+        None => {
+            let mut out = BufWriter::new(io::stdout().lock());
+            aoc_gen::write(args.day, args.size, args.seed, &mut out).and_then(|_| out.flush())
+        }
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+#[derive(Args)]
+struct ParamsArgs {
+    /// Only list this day's parameters
+    #[arg(long)]
+    day: Option<u8>,
+}
+
+fn params(args: ParamsArgs) {
+    let rows: Vec<Vec<String>> = days::DAYS
+        .iter()
+        .filter(|day| args.day.is_none_or(|number| day.number == number))
+        .flat_map(|day| {
+            day.solution.params().into_iter().map(|param| {
+                vec![
+                    day.number.to_string(),
+                    param.name.to_string(),
+                    param.value,
+                    param.help.to_string(),
+                ]
+            })
+        })
+        .collect();
+    print!("{}", table::format(&["day", "name", "default", ""], &rows));
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Day to watch
+    #[arg(long)]
+    day: u8,
+
+    /// How often to check the inputs, in milliseconds
+    #[arg(long, default_value_t = 500)]
+    interval: u64,
+
+    /// Files to watch instead of the day's input and examples
+    inputs: Vec<PathBuf>,
+}
+
+fn watch(args: WatchArgs) {
+    let Some(day) = days::get(args.day) else {
+        eprintln!("error: no solution for day {}", args.day);
+        process::exit(1);
+    };
+    // Without a manifest there's nothing to flag, but watching is still useful
+    let known = manifest::load(&manifest::default_path()).unwrap_or_else(|err| {
+        eprintln!("warning: {}", err);
+        Vec::new()
+    });
+
+    let mut watcher = Watcher::new(day, args.inputs, known);
+    loop {
+        if let Some(report) = watcher.poll() {
+            println!("{}", report);
+        }
+        thread::sleep(Duration::from_millis(args.interval));
+    }
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day to fetch
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Fetch every day that has a solution but no input yet
+    #[arg(long)]
+    all: bool,
+
+    /// Settings file with the session token, defaults to `aoc.toml` in the
+    /// workspace root
+    #[arg(long)]
+    config: Option<PathBuf>,
+}
+
+fn fetch(args: FetchArgs) {
+    let selected: Vec<&Day> = match args.day {
+        Some(number) => match days::get(number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("error: no solution for day {}", number);
+                process::exit(1);
+            }
+        },
+        None => days::DAYS.iter().collect(),
+    };
+    let config_path = args.config.unwrap_or_else(Config::default_path);
+    let config = Config::load(&config_path).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+
+    let mut client = Client::new(config);
+    let mut ok = true;
+    for day in selected {
+        let path = day.default_input();
+        let root = aoc::root();
+        let name = path.strip_prefix(&root).unwrap_or(&path).display();
+        match fetch::fetch_input(&mut client, day.number, &path) {
+            Ok(Fetched::Cached) => println!("day {}: {} is already there", day.number, name),
+            Ok(Fetched::Downloaded(bytes)) => {
+                println!(
+                    "day {}: wrote {} to {}",
+                    day.number,
+                    format_bytes(bytes as u64),
+                    name
+                )
+            }
+            Err(err) => {
+                eprintln!("error: {}", err);
+                ok = false;
+            }
+        }
+    }
+    if !ok {
+        process::exit(1);
+    }
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit
+    #[arg(long)]
+    day: u8,
+
+    /// Part to submit
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Input to run on, defaults to the day's input
+    input: Option<PathBuf>,
+
+    /// Settings file with the session token, defaults to `aoc.toml` in the
+    /// workspace root
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Attempt log, defaults to `submissions.json` in the workspace root
+    #[arg(long)]
+    log: Option<PathBuf>,
+}
+
+fn submit(args: SubmitArgs) {
+    let Some(day) = days::get(args.day) else {
+        eprintln!("error: no solution for day {}", args.day);
+        process::exit(1);
+    };
+    let fail = |err: String| -> ! {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    };
+    let config = Config::load(&args.config.unwrap_or_else(Config::default_path))
+        .unwrap_or_else(|err| fail(err));
+    let log_path = args.log.unwrap_or_else(Log::default_path);
+    let mut log = Log::load(&log_path).unwrap_or_else(|err| fail(err));
+
+    let fname = args.input.unwrap_or_else(|| day.default_input());
+    let input = input::read(&fname).unwrap_or_else(|err| {
+        fail(format!(
+            "couldn't read {}: {}",
+            input::display_name(&fname),
+            err
+        ))
+    });
+    let job = Job {
+        day,
+        name: input::display_name(&fname),
+        input,
+    };
+    let answer = match run::run_part(&job, args.part, &Options::default()) {
+        Ok(record) => record.answer,
+        Err(message) => {
+            eprint!("{}", message);
+            process::exit(1);
+        }
+    };
+
+    let mut client = Client::new(config);
+    let response = submit::submit(&mut client, &mut log, day.number, args.part, &answer)
+        .unwrap_or_else(|err| fail(format!("day {} part {}: {}", day.number, args.part, err)));
+    log.save(&log_path).unwrap_or_else(|err| fail(err));
+
+    println!(
+        "day {} part {}: {} is {}",
+        day.number, args.part, answer, response.verdict
+    );
+    if let Some(seconds) = response.lockout {
+        println!("wait {}s before sending another answer", seconds);
+    }
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to start
+    #[arg(long)]
+    day: u8,
+}
+
+fn new(args: NewArgs) {
+    let root = aoc::root();
+    match scaffold::new_day(&root, args.day) {
+        Ok(paths) => {
+            for path in paths {
+                println!(
+                    "wrote {}",
+                    path.strip_prefix(&root).unwrap_or(&path).display()
+                );
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+#[derive(Args)]
+struct ReplArgs {
+    /// Day to load
+    #[arg(long)]
+    day: u8,
+
+    /// Puzzle input to load. Defaults to the day's `input` file
+    input: Option<PathBuf>,
+}
+
+fn repl(args: ReplArgs) {
+    let Some(day) = days::get(args.day) else {
+        eprintln!("error: no solution for day {}", args.day);
+        process::exit(1);
+    };
+    let fname = args.input.unwrap_or_else(|| day.default_input());
+    if input::is_stdin(&fname) {
+        eprintln!("error: commands are read from stdin, so the input has to be a file");
+        process::exit(1);
+    }
+    let name = input::display_name(&fname);
+    let input = input::read(&fname).unwrap_or_else(|err| {
+        eprintln!("error: couldn't read {}: {}", name, err);
+        process::exit(1);
+    });
+    let repl = Repl::new(day, &input).unwrap_or_else(|err| {
+        eprint!("{}", err.with_file(&name).render(&input));
+        process::exit(1);
+    });
+
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    if interactive {
+        println!(
+            "day {} loaded from {}, `help` lists commands",
+            day.number, name
+        );
+    }
+    let mut line = String::new();
+    loop {
+        if interactive {
+            print!("> ");
+            io::stdout().flush().unwrap();
+        }
+        line.clear();
+        match stdin.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("error: couldn't read a command: {}", err);
+                process::exit(1);
+            }
+        }
+        match repl.eval(&line) {
+            Some(output) if output.is_empty() => {}
+            Some(output) => println!("{}", output),
+            None => break,
+        }
+    }
+}
+
+/// Prints what each of `selected` allocates, returning whether every day ran.
+fn mem_report(selected: &[&Day]) -> bool {
+    if !mem::ENABLED {
+        eprintln!("error: --mem needs the counting allocator, build with `--features mem`");
+        process::exit(1);
+    }
+
+    let mut memory = Vec::new();
+    let mut ok = true;
+    for day in selected {
+        let fname = day.default_input();
+        let input = match input::read(&fname) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: couldn't read {}: {}", fname.display(), err);
+                ok = false;
+                continue;
+            }
+        };
+        match bench::mem_day(day, &input) {
+            Ok(benched) => {
+                memory.extend(benched.rows);
+                benched.failed.iter().for_each(|err| eprint!("{}", err));
+                ok = ok && benched.failed.is_empty();
+            }
+            Err(err) => {
+                eprint!("{}", err.with_file(&fname).render(&input));
+                ok = false;
+            }
+        }
+    }
+    print!("{}", bench::mem_report(&memory));
+    ok
+}
+
+fn bench(args: BenchArgs) {
+    let selected: Vec<&Day> = match args.day {
+        Some(number) => match days::get(number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("error: no solution for day {}", number);
+                process::exit(1);
+            }
+        },
+        None => days::DAYS.iter().collect(),
+    };
+
+    if args.mem {
+        if !mem_report(&selected) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let history_path = args.history.unwrap_or_else(History::default_path);
+    let mut history = History::load(&history_path).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+
+    let mut timings = Vec::new();
+    let mut ok = true;
+    for day in selected {
+        let fname = day.default_input();
+        let input = match input::read(&fname) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: couldn't read {}: {}", fname.display(), err);
+                ok = false;
+                continue;
+            }
+        };
+        match bench::bench_day(day, &input, args.iterations) {
+            Ok(benched) => {
+                timings.extend(benched.rows);
+                benched.failed.iter().for_each(|err| eprint!("{}", err));
+                ok = ok && benched.failed.is_empty();
+            }
+            Err(err) => {
+                eprint!("{}", err.with_file(&fname).render(&input));
+                ok = false;
+            }
+        }
+    }
+
+    print!("{}", bench::report(&timings, &history, args.threshold));
+
+    // What did run is still saved, so one broken day doesn't lose the rest
+    history.push(args.iterations, timings);
+    if let Err(err) = history.save(&history_path) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+    if !ok {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Gen(args) => gen(args),
+        Command::Params(args) => params(args),
+        Command::Watch(args) => watch(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new(args),
+        Command::Repl(args) => repl(args),
+    }
+}
@@ -0,0 +1,109 @@
+use serde::Deserialize;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use crate::{days, input, table};
+
+/// One known answer: running `part` of `day` over `input` should print `expected`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    /// Relative to the workspace root
+    pub input: PathBuf,
+    /// Empty for a placeholder from `aoc new`, which isn't checked
+    #[serde(deserialize_with = "answer_string")]
+    pub expected: String,
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    answer: Vec<Answer>,
+}
+
+pub struct Mismatch {
+    pub answer: Answer,
+    pub actual: String,
+}
+
+// Answers can be written as TOML integers or as strings, for those that don't fit an i64
+fn answer_string<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Int(i64),
+        Str(String),
+    }
+
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Int(value) => value.to_string(),
+        Value::Str(value) => value,
+    })
+}
+
+pub fn default_path() -> PathBuf {
+    crate::root().join("answers.toml")
+}
+
+pub fn load(path: &Path) -> Result<Vec<Answer>, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("couldn't read {}: {}", path.display(), err))?;
+    let manifest: Manifest =
+        toml::from_str(&text).map_err(|err| format!("{}: {}", path.display(), err))?;
+    Ok(manifest.answer)
+}
+
+/// Runs the solution for `answer`, returning what it printed or a description of how it failed.
+pub fn run(answer: &Answer) -> String {
+    let Some(day) = days::get(answer.day) else {
+        return format!("no solution for day {}", answer.day);
+    };
+    let fname = crate::root().join(&answer.input);
+    let input = match input::read(&fname) {
+        Ok(input) => input,
+        Err(err) => return format!("couldn't read {}: {}", fname.display(), err),
+    };
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| match day.solution.parse(&input) {
+        Ok(parsed) => parsed
+            .part(answer.part)
+            .unwrap_or_else(|err| format!("error: {}", err)),
+        Err(err) => format!("error: {}", err),
+    }));
+    result.unwrap_or_else(|_| "panicked".to_string())
+}
+
+pub fn check<'a>(answers: impl IntoIterator<Item = &'a Answer>) -> Vec<Mismatch> {
+    answers
+        .into_iter()
+        .filter(|answer| !answer.expected.is_empty())
+        .filter_map(|answer| {
+            let actual = run(answer);
+            if actual == answer.expected {
+                None
+            } else {
+                Some(Mismatch {
+                    answer: answer.clone(),
+                    actual,
+                })
+            }
+        })
+        .collect()
+}
+
+pub fn mismatch_table(mismatches: &[Mismatch]) -> String {
+    let rows: Vec<Vec<String>> = mismatches
+        .iter()
+        .map(|mismatch| {
+            vec![
+                mismatch.answer.day.to_string(),
+                mismatch.answer.part.to_string(),
+                mismatch.answer.input.display().to_string(),
+                mismatch.answer.expected.clone(),
+                mismatch.actual.clone(),
+            ]
+        })
+        .collect();
+    table::format(&["day", "part", "input", "expected", "actual"], &rows)
+}
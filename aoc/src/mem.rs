@@ -0,0 +1,102 @@
+//! Allocation counting for `aoc bench --mem`. The counting allocator is only
+//! installed when built with the `mem` feature; without it every measurement
+//! comes back as zero. Only heap allocations are seen, so this isn't the
+//! process's resident memory: the stack, memory-mapped files and the
+//! allocator's own overhead are all left out.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+/// Held by `measure`, which resets `PEAK`, so measurements never overlap
+static MEASURING: Mutex<()> = Mutex::new(());
+
+/// Wraps the system allocator, counting allocations and tracking how many bytes
+/// are live at once. Reallocations count as one allocation of the new size.
+pub struct Counting;
+
+impl Counting {
+    fn allocated(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn freed(size: usize) {
+        CURRENT.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Counting::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Counting::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Counting::freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            Counting::freed(layout.size());
+            Counting::allocated(new_size);
+        }
+        new
+    }
+}
+
+#[cfg(feature = "mem")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Whether this build counts allocations at all.
+pub const ENABLED: bool = cfg!(feature = "mem");
+
+/// Allocations made while running something.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    /// Total asked for, including anything freed again
+    pub bytes: u64,
+    /// Most heap bytes live at once, on top of what was live beforehand
+    pub peak_heap: u64,
+}
+
+/// Runs `f`, counting what it allocates. The counters are shared by every
+/// thread, so anything else allocating at the same time is counted too. Other
+/// calls wait for this one to finish, so `f` mustn't call `measure` itself.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Usage) {
+    let _measuring = MEASURING.lock().unwrap_or_else(PoisonError::into_inner);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let start = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak_heap: PEAK.load(Ordering::Relaxed).saturating_sub(start),
+    };
+    (result, usage)
+}
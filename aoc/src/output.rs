@@ -0,0 +1,87 @@
+use aoc_common::Step;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// One `day N part P: answer` line per result
+    Text,
+    /// One JSON object per line: `{"day", "part", "input", "answer", "elapsed_ns"}`,
+    /// plus `"explain"` with --explain
+    Json,
+}
+
+/// The result of running one part of one day. The field names are the JSON schema,
+/// so renaming them breaks anything consuming `--format json`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// Input file the answer came from, `<stdin>` for standard input
+    pub input: String,
+    pub answer: String,
+    pub elapsed_ns: u64,
+    /// Intermediate state behind the answer, only filled in for `--explain`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub explain: Vec<ExplainStep>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExplainStep {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub text: String,
+}
+
+impl From<Step> for ExplainStep {
+    fn from(step: Step) -> Self {
+        ExplainStep {
+            line: step.line,
+            text: step.text,
+        }
+    }
+}
+
+impl Record {
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Text => {
+                let answer = format!("day {} part {}: {}", self.day, self.part, self.answer);
+                format_steps(&self.explain) + &answer
+            }
+            Format::Json => serde_json::to_string(self).unwrap(),
+        }
+    }
+}
+
+/// One indented line per step, to go above the answer or error they explain.
+pub fn format_steps(steps: &[ExplainStep]) -> String {
+    steps
+        .iter()
+        .map(|step| match step.line {
+            Some(line) => format!("  line {}: {}\n", line, step.text),
+            None => format!("  {}\n", step.text),
+        })
+        .collect()
+}
+
+/// Nanoseconds as a short human readable duration, like `3.4ms`.
+pub fn format_ns(nanos: u64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos))
+}
+
+/// Byte count in the largest unit that keeps it at least 1, like `12.3KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1}{}", value, UNITS[unit])
+}
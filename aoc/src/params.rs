@@ -0,0 +1,114 @@
+//! Overrides for the puzzle parameters each day declares, from `--param` and
+//! parameter files.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::days;
+
+/// Parameter values to run each day with. Later values for the same parameter
+/// win, so `--param` can override a file.
+#[derive(Debug, Clone, Default)]
+pub struct Overrides {
+    days: BTreeMap<u8, Vec<(String, String)>>,
+}
+
+/// A TOML value in the text form parameters are set from. Arrays become comma
+/// separated lists.
+fn value_text(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(text) => Some(text.clone()),
+        toml::Value::Integer(_) | toml::Value::Float(_) | toml::Value::Boolean(_) => {
+            Some(value.to_string())
+        }
+        toml::Value::Array(items) => {
+            let items = items.iter().map(value_text).collect::<Option<Vec<_>>>()?;
+            Some(items.join(","))
+        }
+        _ => None,
+    }
+}
+
+fn day_number(key: &str) -> Option<u8> {
+    key.strip_prefix("day")?.parse().ok()
+}
+
+impl Overrides {
+    /// Reads a TOML file with a table for each day, like `[day2]` then `red = 20`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("couldn't read {}: {}", path.display(), err))?;
+        let tables: toml::Table =
+            toml::from_str(&text).map_err(|err| format!("{}: {}", path.display(), err))?;
+
+        let mut overrides = Overrides::default();
+        for (key, table) in &tables {
+            let (Some(day), toml::Value::Table(table)) = (day_number(key), table) else {
+                return Err(format!(
+                    "{}: expected `[dayN]` tables, found `{}`",
+                    path.display(),
+                    key
+                ));
+            };
+            for (name, value) in table {
+                let value = value_text(value).ok_or_else(|| {
+                    format!(
+                        "{}: unsupported value for `{}.{}`",
+                        path.display(),
+                        key,
+                        name
+                    )
+                })?;
+                overrides.set(day, name, &value);
+            }
+        }
+        Ok(overrides)
+    }
+
+    pub fn set(&mut self, day: u8, name: &str, value: &str) {
+        self.days
+            .entry(day)
+            .or_default()
+            .push((name.to_string(), value.to_string()));
+    }
+
+    /// Adds a `--param` argument: `name=value` for `day`, or `dayN.name=value` for
+    /// any day.
+    pub fn add_arg(&mut self, arg: &str, day: Option<u8>) -> Result<(), String> {
+        let (key, value) = arg
+            .split_once('=')
+            .ok_or_else(|| format!("expected `name=value`, found `{}`", arg))?;
+        let (day, name) = match key.split_once('.') {
+            Some((prefix, name)) => {
+                let day = day_number(prefix)
+                    .ok_or_else(|| format!("expected `dayN.{}`, found `{}`", name, key))?;
+                (day, name)
+            }
+            None => {
+                let day =
+                    day.ok_or_else(|| format!("`{}` needs a day, like `day2.{}`", key, key))?;
+                (day, key)
+            }
+        };
+        self.set(day, name.trim(), value);
+        Ok(())
+    }
+
+    /// Values for `day`'s parameters, in the order they were given.
+    pub fn for_day(&self, day: u8) -> &[(String, String)] {
+        self.days.get(&day).map_or(&[], Vec::as_slice)
+    }
+
+    /// Checks every override is for a day that has that parameter, with a value
+    /// it accepts.
+    pub fn check(&self) -> Result<(), String> {
+        for (&number, values) in &self.days {
+            let day = days::get(number).ok_or_else(|| format!("no solution for day {}", number))?;
+            day.solution
+                .with_params(values)
+                .map_err(|err| format!("day {}: {}", number, err))?;
+        }
+        Ok(())
+    }
+}
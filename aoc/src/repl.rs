@@ -0,0 +1,89 @@
+//! `aoc repl`: parses one input, then answers commands about it a line at a time,
+//! so poking at the data doesn't mean a rebuild or a fresh parse each time.
+
+use aoc_common::query;
+use aoc_common::{ParseResult, Parsed};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::days::Day;
+use crate::output::{format_steps, ExplainStep};
+use crate::table;
+
+/// Commands every day has, as name, arguments and help, before its own queries.
+const COMMANDS: [(&str, &str, &str); 4] = [
+    ("part", "<1|2>", "a part's answer"),
+    ("explain", "<1|2>", "the steps behind a part's answer"),
+    ("help", "", "list these commands"),
+    ("quit", "", "leave, as does the end of input"),
+];
+
+pub struct Repl {
+    day: &'static Day,
+    parsed: Box<dyn Parsed>,
+}
+
+impl Repl {
+    pub fn new(day: &'static Day, input: &str) -> ParseResult<Self> {
+        Ok(Repl {
+            day,
+            parsed: day.solution.parse(input)?,
+        })
+    }
+
+    pub fn help(&self) -> String {
+        let commands = COMMANDS.iter().copied().chain(
+            self.day
+                .solution
+                .queries()
+                .into_iter()
+                .map(|query| (query.name, query.args, query.help)),
+        );
+        let rows: Vec<Vec<String>> = commands
+            .map(|(name, args, help)| vec![name.to_string(), args.to_string(), help.to_string()])
+            .collect();
+        table::format(&["command", "args", "help"], &rows)
+    }
+
+    fn part(&self, name: &str, args: &[&str]) -> Result<String, String> {
+        let part = match query::args(name, "<1|2>", args)? {
+            ["1"] => 1,
+            ["2"] => 2,
+            _ => return Err(format!("usage: {} <1|2>", name)),
+        };
+        let answer = self.parsed.part(part).map_err(|err| err.to_string());
+        if name == "part" {
+            return answer;
+        }
+        let steps: Vec<ExplainStep> = self
+            .parsed
+            .explain(part)
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(format_steps(&steps) + &answer.unwrap_or_else(|err| format!("error: {}", err)))
+    }
+
+    /// What to print for one line of commands, or `None` to stop.
+    pub fn eval(&self, line: &str) -> Option<String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let (&name, args) = match words.split_first() {
+            Some(split) => split,
+            None => return Some(String::new()),
+        };
+        if name == "quit" || name == "exit" {
+            return None;
+        }
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| match name {
+            "help" => Ok(self.help()),
+            "part" | "explain" => self.part(name, args),
+            _ => self.parsed.query(name, args),
+        }));
+        let output = match result {
+            Ok(Ok(output)) => output,
+            Ok(Err(err)) => format!("error: {}", err),
+            Err(_) => format!("error: `{}` panicked", name),
+        };
+        Some(output.trim_end().to_string())
+    }
+}
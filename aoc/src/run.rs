@@ -0,0 +1,164 @@
+use aoc_common::{DynSolution, Parsed, Width};
+use std::io::BufRead;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+use crate::days::Day;
+use crate::output::{format_steps, Record};
+use crate::params::Overrides;
+
+/// One input file loaded for one day.
+pub struct Job {
+    pub day: &'static Day,
+    /// Name to report the input under
+    pub name: String,
+    pub input: String,
+}
+
+/// How to run each part.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Keep the intermediate state behind each answer
+    pub explain: bool,
+    /// Accumulator type to work answers out in
+    pub width: Width,
+    pub params: Overrides,
+    /// Read the input a line at a time instead of loading it first
+    pub stream: bool,
+}
+
+/// `day`'s solution with the parameters `options` sets for it.
+fn configure(day: &Day, options: &Options) -> Result<Box<dyn DynSolution>, String> {
+    day.solution
+        .with_params(options.params.for_day(day.number))
+        .map_err(|err| format!("error: day {}: {}\n", day.number, err))
+}
+
+/// Parses `job`'s input once and runs each of `parts` on it, giving one result
+/// per part. A failure comes back as the message to show for it, with parse
+/// errors already rendered against the input. A parse that fails fails every
+/// part the same way.
+pub fn run_parts(job: &Job, parts: &[u8], options: &Options) -> Vec<Result<Record, String>> {
+    let configured;
+    let solution: &dyn DynSolution = match options.params.for_day(job.day.number) {
+        [] => job.day.solution,
+        _ => match configure(job.day, options) {
+            Ok(solution) => {
+                configured = solution;
+                &*configured
+            }
+            Err(message) => return parts.iter().map(|_| Err(message.clone())).collect(),
+        },
+    };
+    let parsed = panic::catch_unwind(AssertUnwindSafe(|| {
+        solution
+            .parse(&job.input)
+            .map_err(|err| err.with_file(&job.name).render(&job.input))
+    }))
+    .unwrap_or_else(|_| {
+        Err(format!(
+            "error: day {} panicked parsing {}\n",
+            job.day.number, job.name
+        ))
+    });
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(message) => return parts.iter().map(|_| Err(message.clone())).collect(),
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            panic::catch_unwind(AssertUnwindSafe(|| solve(job, &*parsed, part, options)))
+                .unwrap_or_else(|_| {
+                    Err(format!(
+                        "error: day {} part {} panicked on {}\n",
+                        job.day.number, part, job.name
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Runs `part` on input that's already parsed.
+fn solve(job: &Job, parsed: &dyn Parsed, part: u8, options: &Options) -> Result<Record, String> {
+    let start = Instant::now();
+    let answer = parsed.part_with(part, options.width);
+    let elapsed = start.elapsed();
+
+    // Explained after timing, and before failing so a broken part is explained too
+    let explain = if options.explain {
+        parsed.explain(part).into_iter().map(Into::into).collect()
+    } else {
+        Vec::new()
+    };
+    let answer = answer.map_err(|err| {
+        format_steps(&explain)
+            + &format!(
+                "error: day {} part {} failed on {}: {}\n",
+                job.day.number, part, job.name, err
+            )
+    })?;
+    Ok(Record {
+        day: job.day.number,
+        part,
+        input: job.name.clone(),
+        answer,
+        elapsed_ns: elapsed.as_nanos() as u64,
+        explain,
+    })
+}
+
+/// Parses `job`'s input and runs just `part` on it, as `run_parts` does.
+pub fn run_part(job: &Job, part: u8, options: &Options) -> Result<Record, String> {
+    run_parts(job, &[part], options).remove(0)
+}
+
+/// Works out `parts` of `day` in one pass over `reader`, for days that can
+/// stream their input. Both parts come from the same pass, so each record is
+/// timed with the whole of it. A failure of the pass is the message for every
+/// part.
+pub fn stream_parts(
+    day: &'static Day,
+    name: &str,
+    reader: &mut dyn BufRead,
+    parts: &[u8],
+    options: &Options,
+) -> Vec<Result<Record, String>> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let solution = configure(day, options)?;
+        let start = Instant::now();
+        let answers = solution
+            .stream(reader, options.width)
+            .ok_or_else(|| format!("error: day {} can't stream its input\n", day.number))?
+            .map_err(|err| err.render(name))?;
+        Ok((answers, start.elapsed()))
+    }));
+    let result = result.unwrap_or_else(|_| {
+        Err(format!(
+            "error: day {} panicked streaming {}\n",
+            day.number, name
+        ))
+    });
+
+    parts
+        .iter()
+        .map(|&part| {
+            let (answers, elapsed) = result.as_ref().map_err(Clone::clone)?;
+            let answer = answers[part as usize - 1].clone().map_err(|err| {
+                format!(
+                    "error: day {} part {} failed on {}: {}\n",
+                    day.number, part, name, err
+                )
+            })?;
+            Ok(Record {
+                day: day.number,
+                part,
+                input: name.to_string(),
+                answer,
+                elapsed_ns: elapsed.as_nanos() as u64,
+                explain: Vec::new(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,137 @@
+//! `aoc new`: starts a crate for a new day from the templates in
+//! `aoc/templates/day` and wires it into the workspace and the runner.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml");
+const MAIN_RS: &str = include_str!("../templates/day/main.rs");
+const LIB_RS: &str = include_str!("../templates/day/lib.rs");
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("couldn't read {}: {}", path.display(), err))
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    fs::write(path, text).map_err(|err| format!("couldn't write {}: {}", path.display(), err))
+}
+
+/// The `N` right after `prefix` in `line`, like `"day` in `    "day3",`.
+fn day_in(line: &str, prefix: &str) -> Option<u8> {
+    let start = line.find(prefix)? + prefix.len();
+    let digits: String = line[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+/// Adds `new_line` among the lines that mention a day after `prefix`, keeping
+/// them in day order. `None` if no line mentions a day.
+fn insert_line(text: &str, prefix: &str, day: u8, new_line: &str) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let numbered: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, day_in(line, prefix)?)))
+        .collect();
+    let index = match numbered.iter().rev().find(|&&(_, other)| other < day) {
+        Some(&(index, _)) => index + 1,
+        None => numbered.first()?.0,
+    };
+
+    let mut lines = lines;
+    lines.insert(index, new_line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// The file at `path` after `edit`, failing with `what` if it can't be applied.
+fn edit(
+    path: &Path,
+    what: &str,
+    edit: impl FnOnce(&str) -> Option<String>,
+) -> Result<(PathBuf, String), String> {
+    let text = read(path)?;
+    let edited =
+        edit(&text).ok_or_else(|| format!("{}: couldn't find {}", path.display(), what))?;
+    Ok((path.to_path_buf(), edited))
+}
+
+/// Adds one to the length in `pub const DAYS: [Day; N]`.
+fn grow_days(text: &str) -> Option<String> {
+    const START: &str = "pub const DAYS: [Day; ";
+    let start = text.find(START)? + START.len();
+    let end = start + text[start..].find(']')?;
+    let count: usize = text[start..end].parse().ok()?;
+    Some(format!("{}{}{}", &text[..start], count + 1, &text[end..]))
+}
+
+fn stub_answers(day: u8) -> String {
+    let mut text = String::from(
+        "\n# Placeholders from `aoc new`, skipped until `expected` is filled in with\n\
+         # the example answers from the puzzle\n",
+    );
+    for part in [1, 2] {
+        text += &format!(
+            "\n[[answer]]\nday = {}\npart = {}\ninput = \"day{}/test\"\nexpected = \"\"\n",
+            day, part, day
+        );
+    }
+    text
+}
+
+/// Creates `dayN` under `root` with an empty input and example, then adds it to
+/// the workspace, the runner's dependencies and day table, and `answers.toml`.
+/// Returns every file created or changed.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if day == 0 || day > 25 {
+        return Err(format!("day {} isn't a puzzle, days go from 1 to 25", day));
+    }
+    let dir = root.join(format!("day{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let fill = |template: &str| template.replace("{day}", &day.to_string());
+    let created = [
+        (dir.join("Cargo.toml"), fill(CARGO_TOML)),
+        (dir.join("src").join("lib.rs"), fill(LIB_RS)),
+        (dir.join("src").join("main.rs"), fill(MAIN_RS)),
+        (dir.join("input"), String::new()),
+        (dir.join("test"), String::new()),
+    ];
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc").join("Cargo.toml");
+    let days = root.join("aoc").join("src").join("days.rs");
+    let answers = root.join("answers.toml");
+    // Every edit is worked out before anything is written, so a failure leaves
+    // the tree alone
+    let edited = [
+        edit(
+            &workspace,
+            "the day crates in the workspace members",
+            |text| insert_line(text, "\"day", day, &format!("    \"day{}\",", day)),
+        )?,
+        edit(&runner, "the day crate dependencies", |text| {
+            let dependency = format!("day{} = {{ path = \"../day{}\" }}", day, day);
+            insert_line(text, "\"../day", day, &dependency)
+        })?,
+        edit(&days, "the `DAYS` table", |text| {
+            let entry = format!("    day!({}, day{}::Day{}),", day, day, day);
+            insert_line(&grow_days(text)?, "day!(", day, &entry)
+        })?,
+        (answers.clone(), read(&answers)? + &stub_answers(day)),
+    ];
+
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|err| format!("couldn't create {}: {}", dir.display(), err))?;
+    for (path, text) in created.iter().chain(&edited) {
+        write(path, text)?;
+    }
+
+    Ok(created
+        .into_iter()
+        .chain(edited)
+        .map(|(path, _)| path)
+        .collect())
+}
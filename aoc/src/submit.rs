@@ -0,0 +1,265 @@
+//! `aoc submit`: sends an answer to the site and keeps a log of every attempt,
+//! so answers that are already known to be wrong are never sent twice.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::client::Client;
+use crate::fetch::YEAR;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Sent too soon after the last attempt, so it wasn't checked
+    Wait,
+    /// The part was already solved, or isn't unlocked yet
+    WrongLevel,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wait => "not checked, sent too soon",
+            Verdict::WrongLevel => "not checked, the part is already solved or still locked",
+        };
+        f.write_str(text)
+    }
+}
+
+/// What the site said about an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// Seconds before another answer will be checked, if the site said
+    pub lockout: Option<u64>,
+}
+
+fn number_word(word: &str) -> Option<u64> {
+    match word {
+        "one" | "a" | "an" => Some(1),
+        _ => word.parse().ok(),
+    }
+}
+
+/// Finds the wait in "you have 1m 30s left to wait" or "please wait 5 minutes
+/// before trying again". A wait too long to count in seconds isn't read.
+fn lockout(text: &str) -> Option<u64> {
+    if let Some(end) = text.find(" left to wait") {
+        let start = text[..end].rfind("you have ")? + "you have ".len();
+        return text[start..end]
+            .split_whitespace()
+            .try_fold(0u64, |total, part| {
+                let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+                let number: u64 = number.parse().ok()?;
+                let seconds = match unit {
+                    "h" => number.checked_mul(3600)?,
+                    "m" => number.checked_mul(60)?,
+                    "s" => number,
+                    _ => return None,
+                };
+                total.checked_add(seconds)
+            });
+    }
+
+    let words: Vec<&str> = text.split_whitespace().collect();
+    words.windows(3).find_map(|window| match window {
+        ["wait", number, unit] if unit.starts_with("minute") => {
+            number_word(number)?.checked_mul(60)
+        }
+        ["wait", number, unit] if unit.starts_with("second") => number_word(number),
+        _ => None,
+    })
+}
+
+/// Reads the verdict out of the page the site sends back for an answer.
+pub fn parse_response(page: &str) -> Result<Response, String> {
+    let text = page.to_lowercase();
+    let verdict = if text.contains("that's the right answer") {
+        Verdict::Right
+    } else if text.contains("gave an answer too recently") {
+        Verdict::Wait
+    } else if text.contains("not the right answer") {
+        if text.contains("answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if text.contains("solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return Err("couldn't find a verdict in the response".to_string());
+    };
+    Ok(Response {
+        verdict,
+        lockout: lockout(&text),
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    /// When answers will be checked again, in seconds since the Unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locked_until: Option<u64>,
+}
+
+/// Every answer sent, oldest first.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Log {
+    pub attempts: Vec<Attempt>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+impl Log {
+    pub fn default_path() -> PathBuf {
+        crate::root().join("submissions.json")
+    }
+
+    /// Loads the log at `path`, or an empty one if nothing has been sent yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                serde_json::from_str(&text).map_err(|err| format!("{}: {}", path.display(), err))
+            }
+            Err(_) if !path.exists() => Ok(Log::default()),
+            Err(err) => Err(format!("couldn't read {}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, text + "\n")
+            .map_err(|err| format!("couldn't write {}: {}", path.display(), err))
+    }
+
+    fn attempts(&self, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.part == part)
+    }
+
+    /// The smallest answer known to be too high and the largest known to be too
+    /// low, for answers that are numbers.
+    pub fn bounds(&self, day: u8, part: u8) -> (Option<i128>, Option<i128>) {
+        let mut below = None;
+        let mut above = None;
+        for attempt in self.attempts(day, part) {
+            let Ok(answer) = attempt.answer.parse::<i128>() else {
+                continue;
+            };
+            match attempt.verdict {
+                Verdict::TooHigh => below = Some(below.map_or(answer, |b: i128| b.min(answer))),
+                Verdict::TooLow => above = Some(above.map_or(answer, |a: i128| a.max(answer))),
+                _ => {}
+            }
+        }
+        (below, above)
+    }
+
+    /// Why `answer` shouldn't be sent, if the log already says how it would go.
+    pub fn refusal(&self, day: u8, part: u8, answer: &str, now: u64) -> Option<String> {
+        if let Some(until) = self
+            .attempts
+            .iter()
+            .filter_map(|attempt| attempt.locked_until)
+            .max()
+        {
+            if until > now {
+                return Some(format!(
+                    "wait {}s before sending another answer",
+                    until - now
+                ));
+            }
+        }
+
+        for attempt in self.attempts(day, part) {
+            match attempt.verdict {
+                Verdict::Right => {
+                    return Some(format!("already solved, the answer was {}", attempt.answer))
+                }
+                Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow if attempt.answer == answer => {
+                    return Some(format!(
+                        "{} was already sent and was {}",
+                        answer, attempt.verdict
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        let value = answer.parse::<i128>().ok()?;
+        match self.bounds(day, part) {
+            (Some(high), _) if value >= high => {
+                Some(format!("{} is too high, {} already was", answer, high))
+            }
+            (_, Some(low)) if value <= low => {
+                Some(format!("{} is too low, {} already was", answer, low))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, response: Response) {
+        let timestamp = now();
+        self.attempts.push(Attempt {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: response.verdict,
+            timestamp,
+            // A wait running past the end of time is as good as forever
+            locked_until: response
+                .lockout
+                .map(|seconds| timestamp.saturating_add(seconds)),
+        });
+    }
+}
+
+/// Sends `answer` for `part` of `day` unless the log rules it out, then adds
+/// the attempt to the log. Saving the log is left to the caller.
+pub fn submit(
+    client: &mut Client,
+    log: &mut Log,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Response, String> {
+    if answer.trim().is_empty() {
+        return Err("refusing to send an empty answer".to_string());
+    }
+    if let Some(reason) = log.refusal(day, part, answer, now()) {
+        return Err(format!("not sending: {}", reason));
+    }
+
+    let level = part.to_string();
+    let page = client.post(
+        &format!("/{}/day/{}/answer", YEAR, day),
+        &[("level", level.as_str()), ("answer", answer)],
+    )?;
+    let response = parse_response(&page)?;
+    log.record(day, part, answer, response);
+    Ok(response)
+}
@@ -0,0 +1,163 @@
+//! `aoc watch`: reruns a day whenever one of its inputs changes, showing each
+//! answer next to the one before it and against the known answers.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime};
+
+use crate::days::Day;
+use crate::input;
+use crate::manifest::Answer;
+use crate::output::format_ns;
+use crate::table;
+
+/// What a file looked like when last checked. The length is kept as well as the
+/// modification time, since some filesystems only store whole seconds.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// `path` with symlinks, `.` and `..` resolved, so a file matches its known
+/// answers however it was named. Left as it is if it can't be resolved.
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+pub struct Watcher {
+    day: &'static Day,
+    /// Files given on the command line, or `None` to watch the day's input and
+    /// examples, including ones added while watching
+    files: Option<Vec<PathBuf>>,
+    known: Vec<Answer>,
+    stamps: BTreeMap<PathBuf, Stamp>,
+    previous: HashMap<(PathBuf, u8), String>,
+}
+
+impl Watcher {
+    /// Watches `files`, or the day's checked in inputs if it's empty. `known`
+    /// answers are matched to files relative to the workspace root.
+    pub fn new(day: &'static Day, files: Vec<PathBuf>, known: Vec<Answer>) -> Self {
+        Watcher {
+            day,
+            files: (!files.is_empty()).then_some(files),
+            known: known
+                .into_iter()
+                .filter(|answer| answer.day == day.number && !answer.expected.is_empty())
+                .collect(),
+            stamps: BTreeMap::new(),
+            previous: HashMap::new(),
+        }
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        match &self.files {
+            Some(files) => files.clone(),
+            None => {
+                let mut files = vec![self.day.default_input()];
+                files.extend(self.day.example_inputs());
+                files
+            }
+        }
+    }
+
+    /// Reruns every file that changed since the last poll, or every file on the
+    /// first poll. `None` if nothing changed.
+    pub fn poll(&mut self) -> Option<String> {
+        let mut changed = Vec::new();
+        for file in self.files() {
+            let stamp = stamp(&file);
+            if self.stamps.get(&file) != Some(&stamp) {
+                self.stamps.insert(file.clone(), stamp);
+                changed.push(file);
+            }
+        }
+        if changed.is_empty() {
+            return None;
+        }
+
+        let rows: Vec<Vec<String>> = changed.iter().flat_map(|file| self.rerun(file)).collect();
+        Some(table::format(
+            &["input", "part", "answer", "previous", "time", "known"],
+            &rows,
+        ))
+    }
+
+    fn expected(&self, file: &Path, part: u8) -> Option<&str> {
+        let root = crate::root();
+        let file = canonical(file);
+        self.known
+            .iter()
+            .find(|answer| answer.part == part && canonical(&root.join(&answer.input)) == file)
+            .map(|answer| answer.expected.as_str())
+    }
+
+    /// One row for parsing `file`, then one for each part.
+    fn rerun(&mut self, file: &Path) -> Vec<Vec<String>> {
+        let root = crate::root();
+        let name = file
+            .strip_prefix(&root)
+            .unwrap_or(file)
+            .display()
+            .to_string();
+        let input = match input::read(file) {
+            Ok(input) => input,
+            Err(err) => return vec![vec![name, String::new(), format!("error: {}", err)]],
+        };
+
+        let start = Instant::now();
+        let parsed = match panic::catch_unwind(AssertUnwindSafe(|| self.day.solution.parse(&input)))
+        {
+            Ok(Ok(parsed)) => parsed,
+            Ok(Err(err)) => {
+                return vec![vec![name, "parse".to_string(), format!("error: {}", err)]]
+            }
+            Err(_) => return vec![vec![name, "parse".to_string(), "panicked".to_string()]],
+        };
+        let parse_ns = start.elapsed().as_nanos() as u64;
+
+        let mut rows = vec![vec![
+            name.clone(),
+            "parse".to_string(),
+            String::new(),
+            String::new(),
+            format_ns(parse_ns),
+        ]];
+        for part in [1, 2] {
+            let start = Instant::now();
+            let answer = match panic::catch_unwind(AssertUnwindSafe(|| parsed.part(part))) {
+                Ok(Ok(answer)) => answer,
+                Ok(Err(err)) => format!("error: {}", err),
+                Err(_) => "panicked".to_string(),
+            };
+            let elapsed = start.elapsed().as_nanos() as u64;
+
+            let previous = match self
+                .previous
+                .insert((file.to_path_buf(), part), answer.clone())
+            {
+                None => String::new(),
+                Some(previous) if previous == answer => "same".to_string(),
+                Some(previous) => previous,
+            };
+            let known = match self.expected(file, part) {
+                None => String::new(),
+                Some(expected) if expected == answer => "ok".to_string(),
+                Some(expected) => format!("CHANGED, expected {}", expected),
+            };
+            rows.push(vec![
+                name.clone(),
+                part.to_string(),
+                answer,
+                previous,
+                format_ns(elapsed),
+                known,
+            ]);
+        }
+        rows
+    }
+}
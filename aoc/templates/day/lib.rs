@@ -0,0 +1,27 @@
+//! Day {day}.
+
+use aoc_common::parse;
+use aoc_common::{ParseResult, Solution, SolveError, SolveResult};
+
+/// Parses the input into its lines.
+#[derive(Clone)]
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+    type Answer = u64;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Input> {
+        Ok(parse::lines(input)
+            .map(|line| line.text.to_string())
+            .collect())
+    }
+
+    fn part1(&self, _lines: &Self::Input) -> SolveResult<u64> {
+        Err(SolveError::new("not solved yet"))
+    }
+
+    fn part2(&self, _lines: &Self::Input) -> SolveResult<u64> {
+        Err(SolveError::new("not solved yet"))
+    }
+}
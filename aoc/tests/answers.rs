@@ -0,0 +1,14 @@
+use aoc::manifest;
+
+#[test]
+fn known_answers() {
+    let answers = manifest::load(&manifest::default_path()).unwrap();
+    let mismatches = manifest::check(&answers);
+
+    assert!(
+        mismatches.is_empty(),
+        "{} known answers changed:\n{}",
+        mismatches.len(),
+        manifest::mismatch_table(&mismatches)
+    );
+}
@@ -0,0 +1,56 @@
+use aoc::bench::{self, Phase};
+use aoc::days::Day;
+use aoc_common::{ParseResult, Solution, SolveError, SolveResult};
+
+/// A day whose part 1 fails and whose part 2 panics.
+#[derive(Clone)]
+struct Broken;
+
+impl Solution for Broken {
+    type Input = ();
+    type Answer = u32;
+
+    fn parse(&self, _input: &str) -> ParseResult<()> {
+        Ok(())
+    }
+
+    fn part1(&self, _input: &()) -> SolveResult<u32> {
+        Err(SolveError::new("no answer").on_line(1))
+    }
+
+    fn part2(&self, _input: &()) -> SolveResult<u32> {
+        panic!("part 2 gave up")
+    }
+}
+
+const BROKEN: Day = Day {
+    number: 30,
+    solution: &Broken,
+};
+
+#[test]
+fn failed_parts_are_reported_rather_than_timed() {
+    let benched = bench::bench_day(&BROKEN, "", 3).unwrap();
+    let phases: Vec<Phase> = benched.rows.iter().map(|timing| timing.phase).collect();
+    assert_eq!(phases, [Phase::Parse]);
+    assert_eq!(
+        benched.failed,
+        [
+            "error: day 30 part 1 failed on input: line 1: no answer\n",
+            "error: day 30 part 2 panicked on input\n",
+        ]
+    );
+
+    let measured = bench::mem_day(&BROKEN, "").unwrap();
+    assert_eq!(measured.rows.len(), 1);
+    assert_eq!(measured.failed, benched.failed);
+}
+
+#[test]
+fn working_days_time_every_phase() {
+    let day = aoc::days::get(9).unwrap();
+    let benched = bench::bench_day(day, "0 3 6 9 12 15\n", 2).unwrap();
+    let phases: Vec<Phase> = benched.rows.iter().map(|timing| timing.phase).collect();
+    assert_eq!(phases, [Phase::Parse, Phase::Part1, Phase::Part2]);
+    assert!(benched.failed.is_empty());
+}
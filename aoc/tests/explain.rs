@@ -0,0 +1,72 @@
+use aoc::days;
+use aoc::run::{self, Job, Options};
+use std::fs;
+
+fn explain_options() -> Options {
+    Options {
+        explain: true,
+        ..Options::default()
+    }
+}
+
+fn job(number: u8, file: &str) -> Job {
+    let day = days::get(number).unwrap();
+    let input = fs::read_to_string(day.dir().join(file)).unwrap();
+    Job {
+        day,
+        name: file.to_string(),
+        input,
+    }
+}
+
+fn explain(number: u8, file: &str, part: u8) -> Vec<String> {
+    let record = run::run_part(&job(number, file), part, &explain_options()).unwrap();
+    record
+        .explain
+        .iter()
+        .map(|step| match step.line {
+            Some(line) => format!("{}: {}", line, step.text),
+            None => step.text.clone(),
+        })
+        .collect()
+}
+
+#[test]
+fn off_by_default() {
+    let record = run::run_part(&job(9, "test"), 1, &Options::default()).unwrap();
+    assert!(record.explain.is_empty());
+}
+
+#[test]
+fn day2_shows_the_rounds_over_the_limit() {
+    assert_eq!(
+        explain(2, "test", 1),
+        vec![
+            "3: game 3 round 1 shows 20 red, more than the bag holds",
+            "4: game 4 round 3 shows 14 red, more than the bag holds",
+        ]
+    );
+}
+
+#[test]
+fn day5_follows_each_seed() {
+    let steps = explain(5, "test", 1);
+    assert_eq!(
+        steps[0],
+        "seed 79 -> 81 -> 81 -> 81 -> 74 -> 78 -> 78 -> 82"
+    );
+    assert_eq!(steps.len(), 4);
+}
+
+#[test]
+fn day9_shows_the_pyramid() {
+    let steps = explain(9, "test", 2);
+    assert_eq!(&steps[..2], ["1: [-3] 0 3 6 9 12 15", "1:   [3] 3 3 3 3 3"]);
+}
+
+#[test]
+fn failures_are_explained_up_to_the_error() {
+    let message = run::run_part(&job(1, "test2"), 1, &explain_options()).unwrap_err();
+    assert!(message.contains("  line 1: two1nine: 1 and 1\n"));
+    assert!(message.ends_with("line 2: no digits\n"));
+}
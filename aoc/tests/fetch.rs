@@ -0,0 +1,128 @@
+mod mock;
+
+use aoc::client::{Client, Config};
+use aoc::fetch::{fetch_input, Fetched};
+use mock::Mock;
+use std::path::PathBuf;
+use std::time::Instant;
+use std::{env, fs, process};
+
+fn config(mock: &Mock, interval_ms: u64) -> Config {
+    Config {
+        session: Some("abc123".to_string()),
+        base_url: mock.url.clone(),
+        interval_ms,
+        stamp: None,
+    }
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn downloads_once_then_uses_the_cache() {
+    let mock = Mock::start(|request| (200, format!("input for {}\n", request.path)));
+    let dir = temp_dir("cache");
+    let path = dir.join("day4").join("input");
+    let mut client = Client::new(config(&mock, 0));
+
+    assert_eq!(
+        fetch_input(&mut client, 4, &path),
+        Ok(Fetched::Downloaded(28))
+    );
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "input for /2023/day/4/input\n"
+    );
+    assert_eq!(fetch_input(&mut client, 4, &path), Ok(Fetched::Cached));
+    fs::remove_dir_all(&dir).unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].body, "");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+}
+
+#[test]
+fn requests_are_spaced_out() {
+    let mock = Mock::start(|_| (200, "1\n".to_string()));
+    let dir = temp_dir("rate");
+    let mut client = Client::new(config(&mock, 300));
+
+    let start = Instant::now();
+    for day in 1..=3 {
+        fetch_input(&mut client, day, &dir.join(day.to_string())).unwrap();
+    }
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(start.elapsed().as_millis() >= 600, "{:?}", start.elapsed());
+    assert_eq!(mock.requests().len(), 3);
+}
+
+#[test]
+fn the_interval_carries_over_between_runs() {
+    let mock = Mock::start(|_| (200, "1\n".to_string()));
+    let dir = temp_dir("stamp");
+    fs::create_dir_all(&dir).unwrap();
+    let stamped = Config {
+        stamp: Some(dir.join("stamp")),
+        ..config(&mock, 300)
+    };
+
+    let start = Instant::now();
+    fetch_input(&mut Client::new(stamped.clone()), 1, &dir.join("1")).unwrap();
+    fetch_input(&mut Client::new(stamped), 2, &dir.join("2")).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(start.elapsed().as_millis() >= 300, "{:?}", start.elapsed());
+}
+
+#[test]
+fn failures_leave_nothing_behind() {
+    let mock = Mock::start(|_| (400, "Please log in".to_string()));
+    let dir = temp_dir("fail");
+    let path = dir.join("input");
+    let mut client = Client::new(config(&mock, 0));
+
+    let err = fetch_input(&mut client, 1, &path).unwrap_err();
+    assert!(
+        err.ends_with("returned 400: the session token was rejected"),
+        "{}",
+        err
+    );
+    assert!(!path.exists());
+
+    let mut client = Client::new(Config {
+        session: None,
+        ..config(&mock, 0)
+    });
+    let err = fetch_input(&mut client, 1, &path).unwrap_err();
+    assert!(
+        err.starts_with("no session token, set AOC_SESSION"),
+        "{}",
+        err
+    );
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[test]
+fn config_files_are_read() {
+    let dir = temp_dir("config");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("aoc.toml");
+    fs::write(
+        &path,
+        "base_url = \"http://localhost:1\"\ninterval_ms = 10\n",
+    )
+    .unwrap();
+    let config = Config::load(&path).unwrap();
+    assert_eq!(config.interval_ms, 10);
+
+    fs::write(&path, "sesion = \"typo\"\n").unwrap();
+    assert!(Config::load(&path)
+        .unwrap_err()
+        .contains("unknown field `sesion`"));
+    fs::remove_dir_all(&dir).unwrap();
+}
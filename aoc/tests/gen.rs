@@ -0,0 +1,38 @@
+use aoc::days::DAYS;
+
+fn generate(day: u8, size: usize, seed: u64) -> String {
+    let mut out = Vec::new();
+    aoc_gen::write(day, size, seed, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn generated_inputs_parse_and_solve() {
+    for day in DAYS.iter() {
+        for seed in 0..5 {
+            // Small enough that no answer overflows, which bigger inputs are free to
+            let input = generate(day.number, 4, seed);
+            let parsed = day.solution.parse(&input).unwrap_or_else(|err| {
+                panic!("day {} seed {}:\n{}", day.number, seed, err.render(&input))
+            });
+            for part in 1..=2 {
+                if let Err(err) = parsed.part(part) {
+                    panic!("day {} part {} seed {}: {}", day.number, part, seed, err);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn same_seed_same_input() {
+    for day in DAYS.iter() {
+        assert_eq!(generate(day.number, 10, 42), generate(day.number, 10, 42));
+        assert_ne!(generate(day.number, 10, 42), generate(day.number, 10, 43));
+    }
+}
+
+#[test]
+fn unknown_day() {
+    assert!(aoc_gen::write(26, 10, 0, &mut Vec::new()).is_err());
+}
@@ -0,0 +1,55 @@
+use aoc::mem;
+use aoc::output::format_bytes;
+use std::hint::black_box;
+
+#[test]
+fn bytes_are_formatted_in_binary_units() {
+    assert_eq!(format_bytes(0), "0B");
+    assert_eq!(format_bytes(1023), "1023B");
+    assert_eq!(format_bytes(1536), "1.5KiB");
+    assert_eq!(format_bytes(5 << 30), "5.0GiB");
+}
+
+// Other tests can allocate at the same time, so only lower bounds hold
+#[cfg(feature = "mem")]
+#[test]
+fn allocations_are_counted() {
+    let (_, usage) = mem::measure(|| {
+        let big = black_box(vec![0u8; 4096]);
+        drop(big);
+        black_box(vec![0u8; 1024])
+    });
+    assert!(usage.allocations >= 2, "{:?}", usage);
+    assert!(usage.bytes >= 5120, "{:?}", usage);
+    assert!(usage.peak_heap >= 4096, "{:?}", usage);
+}
+
+#[cfg(feature = "mem")]
+#[test]
+fn measurements_on_other_threads_dont_reset_the_peak() {
+    use std::thread;
+
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            thread::spawn(|| {
+                let (_, usage) = mem::measure(|| {
+                    let big = black_box(vec![0u8; 4096]);
+                    thread::yield_now();
+                    drop(big);
+                });
+                usage
+            })
+        })
+        .collect();
+    for thread in threads {
+        let usage = thread.join().unwrap();
+        assert!(usage.peak_heap >= 4096, "{:?}", usage);
+    }
+}
+
+#[cfg(not(feature = "mem"))]
+#[test]
+fn nothing_is_counted_without_the_feature() {
+    let (_, usage) = mem::measure(|| black_box(vec![0u8; 4096]));
+    assert_eq!(usage, mem::Usage::default());
+}
@@ -0,0 +1,70 @@
+//! A local stand-in for the Advent of Code site, recording every request.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tiny_http::{Header, Response, Server};
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+pub struct Mock {
+    pub url: String,
+    server: Arc<Server>,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl Mock {
+    /// Serves every request with the status and body `respond` gives for it.
+    pub fn start(respond: impl Fn(&Request) -> (u16, String) + Send + 'static) -> Mock {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let (incoming, seen) = (server.clone(), requests.clone());
+        thread::spawn(move || {
+            for mut request in incoming.incoming_requests() {
+                let mut body = String::new();
+                let _ = request.as_reader().read_to_string(&mut body);
+                let recorded = Request {
+                    method: request.method().to_string(),
+                    path: request.url().to_string(),
+                    cookie: request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv("Cookie"))
+                        .map(|header| header.value.to_string()),
+                    body,
+                };
+                let (status, text) = respond(&recorded);
+                seen.lock().unwrap().push(recorded);
+                let header = Header::from_bytes("Content-Type", "text/plain").unwrap();
+                let _ = request.respond(
+                    Response::from_string(text)
+                        .with_status_code(status)
+                        .with_header(header),
+                );
+            }
+        });
+
+        Mock {
+            url,
+            server,
+            requests,
+        }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for Mock {
+    fn drop(&mut self) {
+        self.server.unblock();
+    }
+}
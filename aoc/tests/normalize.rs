@@ -0,0 +1,121 @@
+use aoc::days::DAYS;
+use aoc::run::{self, Job, Options};
+use aoc_common::input::{normalize, Irregularity, Lines, Normalizer};
+use std::fs;
+use std::io::{self, BufRead};
+
+/// `input` the way an editor on another machine might have left it.
+fn mangle(input: &str) -> String {
+    let lines: Vec<String> = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| match i % 3 {
+            0 => format!("{}\r\n", line),
+            1 => format!("{} \t\n", line),
+            _ => format!("{}\n", line),
+        })
+        .collect();
+    format!("\u{feff}{}\r\n\n \n", lines.concat())
+}
+
+fn answers(number: u8, input: &str) -> Vec<Result<String, String>> {
+    let job = Job {
+        day: DAYS.iter().find(|day| day.number == number).unwrap(),
+        name: "input".to_string(),
+        input: input.to_string(),
+    };
+    [1, 2]
+        .iter()
+        .map(|&part| run::run_part(&job, part, &Options::default()).map(|record| record.answer))
+        .collect()
+}
+
+#[test]
+fn checked_in_inputs_are_already_tidy() {
+    for day in DAYS.iter() {
+        let mut fnames = vec![day.default_input()];
+        fnames.extend(day.example_inputs());
+        for fname in fnames {
+            let input = fs::read_to_string(&fname).unwrap();
+            let normalized = normalize(&input);
+            assert_eq!(normalized.text, input, "{}", fname.display());
+            assert_eq!(normalized.irregularities, [], "{}", fname.display());
+        }
+    }
+}
+
+#[test]
+fn every_day_answers_the_same_after_tidying() {
+    for day in DAYS.iter() {
+        for fname in day.example_inputs() {
+            let input = fs::read_to_string(&fname).unwrap();
+            let tidied = normalize(&mangle(&input)).text;
+            assert_eq!(tidied, input, "{}", fname.display());
+            assert_eq!(
+                answers(day.number, &tidied),
+                answers(day.number, &input),
+                "{}",
+                fname.display()
+            );
+        }
+    }
+}
+
+#[test]
+fn irregularities_are_tallied() {
+    let normalized = normalize("\u{feff}a\r\nb \nc\td\r\n\n\ne\n\n \n\r\n");
+    assert_eq!(normalized.text, "a\nb\nc d\n\n\ne\n");
+    assert_eq!(
+        normalized.irregularities,
+        [
+            Irregularity::Bom,
+            Irregularity::CrLf(Lines { count: 3, first: 1 }),
+            Irregularity::TrailingWhitespace(Lines { count: 2, first: 2 }),
+            Irregularity::Tabs(Lines { count: 1, first: 3 }),
+            Irregularity::TrailingBlankLines(3),
+        ]
+    );
+    let warnings: Vec<String> = normalized
+        .irregularities
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        warnings,
+        [
+            "removed a byte order mark",
+            "CRLF line endings on 3 lines, from line 1",
+            "trailing whitespace on 2 lines, from line 2",
+            "tabs read as spaces on line 3",
+            "dropped 3 blank lines at the end",
+        ]
+    );
+}
+
+#[test]
+fn a_missing_final_newline_is_added_quietly() {
+    assert_eq!(normalize("a\nb"), normalize("a\nb\n"));
+    assert_eq!(normalize("").text, "");
+    assert_eq!(normalize("\n\n").text, "");
+}
+
+#[test]
+fn reading_a_line_at_a_time_tidies_the_same_way() {
+    let raw = mangle("0 3 6\n\n1 3 6\n2 3 6\n");
+    let mut reader = Normalizer::new(raw.as_bytes());
+    let lines: Vec<String> = (&mut reader).lines().collect::<io::Result<_>>().unwrap();
+    assert_eq!(lines, ["0 3 6", "", "1 3 6", "2 3 6"]);
+
+    let whole = normalize(&raw);
+    assert_eq!(reader.irregularities(), whole.irregularities);
+    assert_eq!(lines.join("\n") + "\n", whole.text);
+}
+
+#[test]
+fn errors_keep_their_line_numbers() {
+    let input = normalize("\u{feff}0 3 6\r\n1 x 6\r\n").text;
+    assert_eq!(
+        answers(9, &input)[0],
+        Err("error: invalid number: invalid digit found in string\n --> input:2:3\n  |\n2 | 1 x 6\n  |   ^\n".to_string())
+    );
+}
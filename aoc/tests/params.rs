@@ -0,0 +1,136 @@
+use aoc::days;
+use aoc::params::Overrides;
+use aoc::run::{self, Job, Options};
+use std::{env, fs, process};
+
+fn run_with(number: u8, input: &str, part: u8, params: Overrides) -> Result<String, String> {
+    let job = Job {
+        day: days::get(number).unwrap(),
+        name: "input".to_string(),
+        input: input.to_string(),
+    };
+    let options = Options {
+        params,
+        ..Options::default()
+    };
+    run::run_part(&job, part, &options).map(|record| record.answer)
+}
+
+fn overrides(day: u8, args: &[&str]) -> Overrides {
+    let mut overrides = Overrides::default();
+    for arg in args {
+        overrides.add_arg(arg, Some(day)).unwrap();
+    }
+    overrides.check().unwrap();
+    overrides
+}
+
+#[test]
+fn every_day_lists_defaults_it_accepts() {
+    for day in days::DAYS.iter() {
+        let defaults: Vec<(String, String)> = day
+            .solution
+            .params()
+            .into_iter()
+            .map(|param| (param.name.to_string(), param.value))
+            .collect();
+        let configured = day.solution.with_params(&defaults).unwrap();
+        assert_eq!(configured.params(), day.solution.params());
+    }
+}
+
+#[test]
+fn day2_bag_size() {
+    let games = "Game 1: 20 red, 2 blue\nGame 2: 3 green\n";
+    assert_eq!(run_with(2, games, 1, Overrides::default()).unwrap(), "2");
+    assert_eq!(
+        run_with(2, games, 1, overrides(2, &["red=20"])).unwrap(),
+        "3"
+    );
+}
+
+#[test]
+fn day1_vocabulary() {
+    let words = "words=un,deux,trois,quatre,cinq,six,sept,huit,neuf";
+    assert_eq!(
+        run_with(1, "deux7x\nhuitrois\n", 2, overrides(1, &[words])).unwrap(),
+        "110"
+    );
+}
+
+#[test]
+fn day3_gear_rule() {
+    let schematic = "1#2\n...\n";
+    assert_eq!(
+        run_with(3, schematic, 2, Overrides::default()).unwrap(),
+        "0"
+    );
+    assert_eq!(
+        run_with(3, schematic, 2, overrides(3, &["gear=#"])).unwrap(),
+        "2"
+    );
+    assert_eq!(
+        run_with(3, "1*..\n", 2, overrides(3, &["gear_parts=1"])).unwrap(),
+        "1"
+    );
+}
+
+#[test]
+fn day8_start_and_end() {
+    let maps = "L\n\nAAA = (BBB, BBB)\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+    assert_eq!(run_with(8, maps, 1, Overrides::default()).unwrap(), "2");
+    assert_eq!(
+        run_with(8, maps, 1, overrides(8, &["end=BBB"])).unwrap(),
+        "1"
+    );
+    assert_eq!(
+        run_with(8, maps, 1, overrides(8, &["start=BBB"])).unwrap(),
+        "1"
+    );
+}
+
+#[test]
+fn bad_overrides_are_caught_up_front() {
+    let mut overrides = Overrides::default();
+    assert!(overrides.add_arg("red", Some(2)).is_err());
+    assert!(overrides.add_arg("red=1", None).is_err());
+
+    overrides.add_arg("day2.purple=1", None).unwrap();
+    assert_eq!(
+        overrides.check().unwrap_err(),
+        "day 2: no parameter `purple`"
+    );
+
+    let mut overrides = Overrides::default();
+    overrides.add_arg("day1.words=one,two", None).unwrap();
+    assert_eq!(
+        overrides.check().unwrap_err(),
+        "day 1: `words` needs 9 entries, found 2"
+    );
+}
+
+#[test]
+fn param_files() {
+    let path = env::temp_dir().join(format!("aoc-params-{}.toml", process::id()));
+    fs::write(
+        &path,
+        "[day2]\nred = 20\n\n[day1]\nwords = [\"un\", \"deux\", \"trois\", \"quatre\", \"cinq\", \"six\", \"sept\", \"huit\", \"neuf\"]\n",
+    )
+    .unwrap();
+    let mut overrides = Overrides::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        overrides.for_day(2),
+        [("red".to_string(), "20".to_string())]
+    );
+    assert_eq!(
+        overrides.for_day(1)[0].1,
+        "un,deux,trois,quatre,cinq,six,sept,huit,neuf"
+    );
+
+    // --param comes after the file, so it wins
+    overrides.add_arg("red=1", Some(2)).unwrap();
+    let games = "Game 1: 20 red\nGame 2: 1 red\n";
+    assert_eq!(run_with(2, games, 1, overrides).unwrap(), "2");
+}
@@ -0,0 +1,20 @@
+use aoc::pool;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn results_keep_task_order() {
+    // Earlier tasks sleep longer, so they finish last
+    let tasks: Vec<u64> = (0..8).collect();
+    let results = pool::map(&tasks, 4, |&task| {
+        thread::sleep(Duration::from_millis(8 - task));
+        task * 10
+    });
+    assert_eq!(results, (0..8).map(|task| task * 10).collect::<Vec<_>>());
+}
+
+#[test]
+fn handles_no_tasks() {
+    let results: Vec<u8> = pool::map(&[] as &[u8], 4, |&task| task);
+    assert!(results.is_empty());
+}
@@ -0,0 +1,48 @@
+use aoc::days::DAYS;
+use std::fs;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Replays every input in `fuzz/regressions`, each of which once made its day panic
+/// or never finish.
+#[test]
+fn fuzz_regressions_finish() {
+    let mut failures = Vec::new();
+    for day in DAYS.iter() {
+        let dir = aoc::root()
+            .join("fuzz/regressions")
+            .join(format!("day{}", day.number));
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let input = fs::read_to_string(&path).unwrap();
+            let (sender, receiver) = mpsc::channel();
+            thread::spawn(move || {
+                match day.solution.parse(&input) {
+                    Ok(parsed) => {
+                        let _ = parsed.part1();
+                        let _ = parsed.part2();
+                    }
+                    Err(err) => {
+                        err.render(&input);
+                    }
+                }
+                sender.send(()).unwrap();
+            });
+
+            // A panic drops the sender, so this only waits out a hang
+            if receiver.recv_timeout(Duration::from_secs(10)).is_err() {
+                failures.push(path.display().to_string());
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "panicked or hung on:\n{}",
+        failures.join("\n")
+    );
+}
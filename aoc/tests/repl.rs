@@ -0,0 +1,77 @@
+use aoc::days;
+use aoc::repl::Repl;
+use std::fs;
+
+fn repl(number: u8, file: &str) -> Repl {
+    let day = days::get(number).unwrap();
+    let input = fs::read_to_string(day.dir().join(file)).unwrap();
+    Repl::new(day, &input).unwrap()
+}
+
+fn eval(repl: &Repl, line: &str) -> String {
+    repl.eval(line).unwrap()
+}
+
+#[test]
+fn answers_the_examples_queries() {
+    assert_eq!(eval(&repl(5, "test"), "location 79"), "82");
+    assert_eq!(
+        eval(&repl(2, "test"), "bag 3"),
+        "20 red, 13 green and 6 blue, power 1560"
+    );
+    assert_eq!(
+        eval(&repl(7, "test"), "rank KTJJT"),
+        "rank 2 of 5 as TwoPair, and 5 with jokers as FourOfAKind"
+    );
+    let day8 = repl(8, "test");
+    assert_eq!(eval(&day8, "walk AAA 0"), "AAA");
+    assert_eq!(eval(&day8, "walk AAA 6"), "ZZZ");
+    assert_eq!(eval(&day8, "walk BBB 1"), "AAA");
+    assert_eq!(eval(&day8, "walk AAA 18446744073709551615"), "ZZZ");
+}
+
+#[test]
+fn every_day_has_the_parts() {
+    let day9 = repl(9, "test");
+    assert_eq!(eval(&day9, "part 1"), "114");
+    assert_eq!(eval(&day9, "  part   2 "), "2");
+    assert_eq!(eval(&day9, ""), "");
+    assert_eq!(day9.eval("quit"), None);
+    assert_eq!(day9.eval("exit"), None);
+
+    let help = eval(&day9, "help");
+    assert!(help.contains("explain  <1|2>"), "{}", help);
+    assert!(!help.contains("walk"), "{}", help);
+    assert!(eval(&repl(8, "test"), "help").contains("walk     <node> <steps>"));
+}
+
+#[test]
+fn explain_shows_steps_above_the_answer() {
+    let explained = eval(&repl(5, "test"), "explain 1");
+    assert!(explained.starts_with("  seed 79 -> 81"), "{}", explained);
+    assert!(explained.ends_with("\n35"), "{}", explained);
+}
+
+#[test]
+fn mistakes_are_reported_and_the_session_carries_on() {
+    let day7 = repl(7, "test");
+    assert_eq!(eval(&day7, "rank"), "error: usage: rank <hand>");
+    assert_eq!(
+        eval(&day7, "rank AAAAA"),
+        "error: no hand `AAAAA` in the input"
+    );
+    assert_eq!(eval(&day7, "part 3"), "error: usage: part <1|2>");
+    assert_eq!(eval(&day7, "walk AAA 1"), "error: no query `walk`");
+    assert_eq!(eval(&day7, "part 1"), "6440");
+
+    let day5 = repl(5, "test");
+    assert_eq!(
+        eval(&day5, "location -1"),
+        "error: invalid value `-1` for `seed`: invalid digit found in string"
+    );
+    assert_eq!(eval(&repl(2, "test"), "bag 17"), "error: no game 17");
+    assert_eq!(
+        eval(&repl(8, "test"), "walk QQQ 1"),
+        "error: no node `QQQ` in the network"
+    );
+}
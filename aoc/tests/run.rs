@@ -0,0 +1,35 @@
+use aoc::days;
+use aoc::run::{self, Job, Options};
+
+fn job(number: u8, input: &str) -> Job {
+    Job {
+        day: days::get(number).unwrap(),
+        name: "input".to_string(),
+        input: input.to_string(),
+    }
+}
+
+#[test]
+fn every_part_comes_from_one_parse() {
+    let job = job(9, "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n");
+    let records: Vec<(u8, String)> = run::run_parts(&job, &[1, 2], &Options::default())
+        .into_iter()
+        .map(|result| result.map(|record| (record.part, record.answer)).unwrap())
+        .collect();
+    assert_eq!(records, [(1, "114".to_string()), (2, "2".to_string())]);
+    assert_eq!(
+        run::run_part(&job, 2, &Options::default()).unwrap().answer,
+        "2"
+    );
+}
+
+#[test]
+fn a_failed_parse_fails_every_part() {
+    let errors: Vec<String> = run::run_parts(&job(9, "0 3 x\n"), &[1, 2], &Options::default())
+        .into_iter()
+        .map(|result| result.unwrap_err())
+        .collect();
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].contains("invalid number"), "{}", errors[0]);
+    assert_eq!(errors[0], errors[1]);
+}
@@ -0,0 +1,70 @@
+use aoc::manifest;
+use aoc::scaffold::new_day;
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+const REGISTRY: [&str; 4] = [
+    "Cargo.toml",
+    "aoc/Cargo.toml",
+    "aoc/src/days.rs",
+    "answers.toml",
+];
+
+/// A copy of just the files `aoc new` edits.
+fn temp_root(name: &str) -> PathBuf {
+    let root = env::temp_dir().join(format!("aoc-new-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for file in REGISTRY {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::copy(aoc::root().join(file), path).unwrap();
+    }
+    root
+}
+
+fn read(root: &Path, file: &str) -> String {
+    fs::read_to_string(root.join(file)).unwrap()
+}
+
+#[test]
+fn new_days_are_registered() {
+    let root = temp_root("register");
+    let written = new_day(&root, 10).unwrap();
+    assert_eq!(written.len(), 9);
+
+    assert!(read(&root, "Cargo.toml").contains("    \"day9\",\n    \"day10\",\n    \"gen\","));
+    assert!(read(&root, "aoc/Cargo.toml").contains("day10 = { path = \"../day10\" }\n"));
+    let days = read(&root, "aoc/src/days.rs");
+    assert!(days.contains("pub const DAYS: [Day; 10] = ["));
+    assert!(days.contains("    day!(9, day9::Day9),\n    day!(10, day10::Day10),\n];"));
+    assert!(read(&root, "day10/src/main.rs").contains("aoc_common::main(&day10::Day10);"));
+    assert!(read(&root, "day10/Cargo.toml").contains("name = \"day10\""));
+    assert_eq!(read(&root, "day10/input"), "");
+
+    // The stub answers are placeholders, so they aren't checked yet
+    let answers = manifest::load(&root.join("answers.toml")).unwrap();
+    let stubs: Vec<_> = answers.iter().filter(|answer| answer.day == 10).collect();
+    assert_eq!(stubs.len(), 2);
+    assert!(stubs.iter().all(|answer| answer.expected.is_empty()));
+    assert!(manifest::check(stubs).is_empty());
+
+    let err = new_day(&root, 10).unwrap_err();
+    assert!(err.ends_with("day10 already exists"), "{}", err);
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn failures_change_nothing() {
+    let root = temp_root("fail");
+    fs::write(root.join("aoc/src/days.rs"), "// no table here\n").unwrap();
+    let before: Vec<String> = REGISTRY.iter().map(|file| read(&root, file)).collect();
+
+    let err = new_day(&root, 11).unwrap_err();
+    assert!(err.ends_with("couldn't find the `DAYS` table"), "{}", err);
+    assert!(!root.join("day11").exists());
+    let after: Vec<String> = REGISTRY.iter().map(|file| read(&root, file)).collect();
+    assert_eq!(before, after);
+
+    assert!(new_day(&root, 26).is_err());
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,188 @@
+use aoc::days::{self, DAYS};
+use aoc::run::{self, Job, Options};
+use aoc_common::Width;
+use std::fs;
+
+const STREAMING: [u8; 5] = [1, 2, 4, 7, 9];
+
+fn options(width: Width) -> Options {
+    Options {
+        width,
+        stream: true,
+        ..Options::default()
+    }
+}
+
+fn parsed(number: u8, input: &str, width: Width) -> Vec<Result<String, String>> {
+    let job = Job {
+        day: days::get(number).unwrap(),
+        name: "input".to_string(),
+        input: input.to_string(),
+    };
+    [1, 2]
+        .iter()
+        .map(|&part| run::run_part(&job, part, &options(width)).map(|record| record.answer))
+        .collect()
+}
+
+fn streamed(number: u8, input: &str, width: Width) -> Vec<Result<String, String>> {
+    let day = days::get(number).unwrap();
+    run::stream_parts(
+        day,
+        "input",
+        &mut input.as_bytes(),
+        &[1, 2],
+        &options(width),
+    )
+    .into_iter()
+    .map(|result| result.map(|record| record.answer))
+    .collect()
+}
+
+fn assert_same(number: u8, input: &str, width: Width) {
+    assert_eq!(
+        streamed(number, input, width),
+        parsed(number, input, width),
+        "day {} with {} on {:?}",
+        number,
+        width,
+        input
+    );
+}
+
+#[test]
+fn only_line_oriented_days_stream() {
+    for day in DAYS.iter() {
+        let streams = day.solution.stream(&mut &b""[..], Width::Native).is_some();
+        assert_eq!(
+            streams,
+            STREAMING.contains(&day.number),
+            "day {}",
+            day.number
+        );
+    }
+    assert_eq!(
+        streamed(3, "", Width::Native),
+        vec![Err("error: day 3 can't stream its input\n".to_string()); 2]
+    );
+}
+
+#[test]
+fn streamed_answers_match_parsed_ones() {
+    for number in STREAMING {
+        let day = days::get(number).unwrap();
+        let mut inputs = vec![fs::read_to_string(day.default_input()).unwrap()];
+        for fname in day.example_inputs() {
+            inputs.push(fs::read_to_string(fname).unwrap());
+        }
+        for seed in 0..3 {
+            let mut out = Vec::new();
+            aoc_gen::write(number, 50, seed, &mut out).unwrap();
+            inputs.push(String::from_utf8(out).unwrap());
+        }
+
+        for input in &inputs {
+            for width in Width::ALL {
+                assert_same(number, input, width);
+            }
+        }
+    }
+}
+
+#[test]
+fn repeated_hands_rank_as_when_sorted() {
+    let input = "32T3K 765\nKK677 28\n32T3K 10\nKTJJT 220\n32T3K 3\nQQQJA 483\n";
+    assert_same(7, input, Width::Native);
+    assert_eq!(streamed(7, input, Width::Native)[0], Ok("6236".to_string()));
+}
+
+/// Cards whose copies each fit in a `u32` but add up to more, once the last card
+/// is counted. The first 31 win copies of every card up to the last.
+fn too_many_cards() -> String {
+    let mut input = String::new();
+    for card in 1..=33 {
+        let numbers: Vec<String> = (1..=33 - card).map(|n| n.to_string()).collect();
+        let (winning, have) = match card {
+            1..=31 => (numbers.join(" "), numbers.join(" ")),
+            _ => ("1".to_string(), "2".to_string()),
+        };
+        input += &format!("Card {}: {} | {}\n", card, winning, have);
+    }
+    input
+}
+
+#[test]
+fn failures_match_parsed_ones() {
+    let too_many_cards = too_many_cards();
+    let inputs = [
+        // Winning copies of cards past the end of the table
+        (4, "Card 1: 1 2 | 1 2\nCard 2: 3 | 4\n"),
+        (4, "Card 1: 41 48 | 41 48\n"),
+        (
+            4,
+            "Card 1: 1 2 | 1 2\nCard 2: 1 2 3 | 1 2 3\nCard 3: 5 | 6\n",
+        ),
+        (4, &too_many_cards),
+        (9, "2147483647 2147483647\n1 1\n"),
+        (2, "Game 1: 3 blue\nGame 2 4 red\n"),
+        (1, "a1b\nno digits\n"),
+        (7, "KK677 4294967295\n32T3K 4294967295\n"),
+        (7, "QQQJA 4294967295\nKK677 1\nQQQJA 4294967295\n"),
+    ];
+    for (number, input) in inputs {
+        assert_same(number, input, Width::Native);
+    }
+}
+
+#[test]
+fn failures_are_put_down_to_a_line() {
+    let failed = |number: u8, input: &str, part: usize| {
+        streamed(number, input, Width::Native)
+            .remove(part - 1)
+            .unwrap_err()
+    };
+    // The card that wins furthest past the end, rather than the first to win past it
+    assert_eq!(
+        failed(4, "Card 1: 1 2 | 1 2\nCard 2: 1 2 3 | 1 2 3\nCard 3: 5 | 6\n", 2),
+        "error: day 4 part 2 failed on input: line 2: wins copies of cards past the end of the table\n"
+    );
+    assert_eq!(
+        failed(4, &too_many_cards(), 2),
+        "error: day 4 part 2 failed on input: line 33: answer overflowed u32\n"
+    );
+    assert_eq!(
+        streamed(4, &too_many_cards(), Width::U64)[1],
+        Ok("6442450943".to_string())
+    );
+    assert_eq!(
+        failed(7, "KK677 4294967295\n32T3K 4294967295\n", 1),
+        "error: day 7 part 1 failed on input: line 1: answer overflowed u32\n"
+    );
+    // Copies of a hand are put down to the last one dealt
+    assert_eq!(
+        failed(7, "QQQJA 4294967295\nKK677 1\nQQQJA 4294967295\n", 1),
+        "error: day 7 part 1 failed on input: line 3: answer overflowed u32\n"
+    );
+}
+
+#[test]
+fn parse_errors_point_at_their_line() {
+    let [part1, part2] = &streamed(9, "0 3 6\r\n1 x 3\n", Width::Native)[..] else {
+        panic!("expected both parts");
+    };
+    assert_eq!(part1, part2);
+    assert_eq!(
+        part1.clone().unwrap_err(),
+        "error: invalid number: invalid digit found in string\n --> input:2:3\n  |\n2 | 1 x 3\n  |   ^\n"
+    );
+}
+
+#[test]
+fn windows_line_endings_are_read_as_lines() {
+    let input = fs::read_to_string(days::get(9).unwrap().dir().join("test")).unwrap();
+    let crlf = input.replace('\n', "\r\n");
+    assert_eq!(
+        streamed(9, &crlf, Width::Native),
+        vec![Ok("114".to_string()), Ok("2".to_string())]
+    );
+}
@@ -0,0 +1,159 @@
+mod mock;
+
+use aoc::client::{Client, Config};
+use aoc::submit::{parse_response, submit, Log, Response, Verdict};
+use mock::Mock;
+use std::{env, fs, process};
+
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high. \
+    If you're stuck, make sure you're using the full input data. Please wait one minute before \
+    trying again. <a href=\"/2023/day/7\">[Return to Day 7]</a></p></article></main>";
+const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low. \
+    Please wait 5 minutes before trying again.</p></article>";
+const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer to \
+    restoring snow operations.</p></article>";
+const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait after \
+    submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+fn client(mock: &Mock) -> Client {
+    Client::new(Config {
+        session: Some("abc123".to_string()),
+        base_url: mock.url.clone(),
+        interval_ms: 0,
+        stamp: None,
+    })
+}
+
+#[test]
+fn responses_are_understood() {
+    let response = |verdict, lockout| Ok(Response { verdict, lockout });
+    assert_eq!(
+        parse_response(TOO_HIGH),
+        response(Verdict::TooHigh, Some(60))
+    );
+    assert_eq!(
+        parse_response(TOO_LOW),
+        response(Verdict::TooLow, Some(300))
+    );
+    assert_eq!(parse_response(RIGHT), response(Verdict::Right, None));
+    assert_eq!(parse_response(TOO_SOON), response(Verdict::Wait, Some(65)));
+    assert_eq!(
+        parse_response("<p>That's not the right answer. Please wait one minute</p>"),
+        response(Verdict::Wrong, Some(60))
+    );
+    assert_eq!(
+        parse_response(
+            "You don't seem to be solving the right level.  Did you already complete it?"
+        ),
+        response(Verdict::WrongLevel, None)
+    );
+    assert!(parse_response("<html>Maintenance</html>").is_err());
+
+    // Waits that don't fit in a u64 of seconds aren't read, rather than wrapping
+    for page in [
+        "You gave an answer too recently. You have 5124095576030432h left to wait.",
+        "You gave an answer too recently. You have 18446744073709551615s 1s left to wait.",
+        "You gave an answer too recently. Please wait 307445734561825861 minutes.",
+    ] {
+        assert_eq!(
+            parse_response(page),
+            response(Verdict::Wait, None),
+            "{}",
+            page
+        );
+    }
+}
+
+#[test]
+fn bounds_rule_out_answers_before_sending() {
+    let mock = Mock::start(|request| match request.body.as_str() {
+        "level=1&answer=100" => (200, TOO_HIGH.to_string()),
+        "level=1&answer=20" => (200, TOO_LOW.to_string()),
+        _ => (200, RIGHT.to_string()),
+    });
+    let mut client = client(&mock);
+    let mut log = Log::default();
+
+    let sent = submit(&mut client, &mut log, 7, 1, "100").unwrap();
+    assert_eq!(sent.verdict, Verdict::TooHigh);
+    // Forget the lockout so the bounds are what stops the next few
+    log.attempts[0].locked_until = None;
+
+    for (answer, reason) in [
+        ("100", "100 was already sent and was too high"),
+        ("150", "150 is too high, 100 already was"),
+    ] {
+        let err = submit(&mut client, &mut log, 7, 1, answer).unwrap_err();
+        assert_eq!(err, format!("not sending: {}", reason));
+    }
+    submit(&mut client, &mut log, 7, 1, "20").unwrap();
+    log.attempts[1].locked_until = None;
+    assert_eq!(log.bounds(7, 1), (Some(100), Some(20)));
+    assert!(submit(&mut client, &mut log, 7, 1, "5").is_err());
+    // Other parts aren't affected
+    assert_eq!(log.refusal(7, 2, "150", 0), None);
+
+    assert_eq!(
+        submit(&mut client, &mut log, 7, 1, "50").unwrap().verdict,
+        Verdict::Right
+    );
+    assert_eq!(
+        submit(&mut client, &mut log, 7, 1, "60").unwrap_err(),
+        "not sending: already solved, the answer was 50"
+    );
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2023/day/7/answer");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+}
+
+#[test]
+fn lockouts_are_waited_out() {
+    let mock = Mock::start(|_| (200, TOO_SOON.to_string()));
+    let mut client = client(&mock);
+    let mut log = Log::default();
+
+    assert_eq!(
+        submit(&mut client, &mut log, 1, 2, "7").unwrap().verdict,
+        Verdict::Wait
+    );
+    let err = submit(&mut client, &mut log, 3, 1, "8").unwrap_err();
+    assert!(err.starts_with("not sending: wait "), "{}", err);
+    assert_eq!(mock.requests().len(), 1);
+
+    let until = log.attempts[0].locked_until.unwrap();
+    assert_eq!(log.refusal(1, 2, "7", until), None);
+}
+
+#[test]
+fn endless_lockouts_are_recorded_without_overflowing() {
+    let page = "You gave an answer too recently. You have 18446744073709551615s left to wait.";
+    let response = parse_response(page).unwrap();
+    assert_eq!(response.lockout, Some(u64::MAX));
+
+    let mut log = Log::default();
+    log.record(1, 1, "7", response);
+    assert_eq!(log.attempts[0].locked_until, Some(u64::MAX));
+    let err = log.refusal(3, 1, "8", 1_700_000_000).unwrap();
+    assert!(err.starts_with("wait "), "{}", err);
+}
+
+#[test]
+fn the_log_round_trips() {
+    let path = env::temp_dir().join(format!("aoc-submissions-{}.json", process::id()));
+    let mut log = Log::load(&path).unwrap();
+    assert!(log.attempts.is_empty());
+
+    log.record(2, 1, "2617", parse_response(RIGHT).unwrap());
+    log.save(&path).unwrap();
+    let loaded = Log::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.attempts.len(), 1);
+    assert_eq!(loaded.attempts[0].verdict, Verdict::Right);
+    assert_eq!(
+        loaded.refusal(2, 1, "2617", 0).unwrap(),
+        "already solved, the answer was 2617"
+    );
+}
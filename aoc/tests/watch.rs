@@ -0,0 +1,78 @@
+use aoc::days;
+use aoc::manifest::Answer;
+use aoc::watch::Watcher;
+use std::{env, fs, process};
+
+const EXAMPLE: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+
+fn row<'a>(report: &'a str, part: &str) -> Vec<&'a str> {
+    report
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>())
+        .find(|fields| fields.get(1) == Some(&part))
+        .unwrap_or_else(|| panic!("no part {} in\n{}", part, report))
+}
+
+#[test]
+fn reruns_only_when_the_input_changes() {
+    let path = env::temp_dir().join(format!("aoc-watch-{}.txt", process::id()));
+    fs::write(&path, EXAMPLE).unwrap();
+    let known = vec![Answer {
+        day: 9,
+        part: 1,
+        input: path.clone(),
+        expected: "114".to_string(),
+    }];
+    let mut watcher = Watcher::new(days::get(9).unwrap(), vec![path.clone()], known);
+
+    let first = watcher.poll().unwrap();
+    assert_eq!(row(&first, "1")[2], "114");
+    assert!(first.lines().any(|line| line.contains("parse")));
+    assert!(row(&first, "1").ends_with(&["ok"]));
+    assert_eq!(watcher.poll(), None);
+
+    fs::write(&path, "0 3 6 9 12 15\n").unwrap();
+    let second = watcher.poll().unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let part1 = row(&second, "1");
+    assert_eq!(&part1[2..4], &["18", "114"]);
+    assert!(second.contains("CHANGED, expected 114"), "{}", second);
+    assert_eq!(&row(&second, "2")[2..4], &["-3", "2"]);
+}
+
+#[test]
+fn unchanged_answers_are_marked_same() {
+    let path = env::temp_dir().join(format!("aoc-watch-same-{}.txt", process::id()));
+    fs::write(&path, EXAMPLE).unwrap();
+    let mut watcher = Watcher::new(days::get(9).unwrap(), vec![path.clone()], Vec::new());
+    watcher.poll().unwrap();
+
+    // Same answers from a different input
+    fs::write(&path, EXAMPLE.replace(' ', "  ")).unwrap();
+    let report = watcher.poll().unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(&row(&report, "1")[2..4], &["114", "same"]);
+}
+
+#[test]
+fn known_answers_match_however_the_file_is_named() {
+    let name = format!("aoc-watch-named-{}.txt", process::id());
+    let path = env::temp_dir().join(&name);
+    fs::write(&path, EXAMPLE).unwrap();
+    let known = vec![Answer {
+        day: 9,
+        part: 1,
+        input: path.clone(),
+        expected: "114".to_string(),
+    }];
+    let dir = env::temp_dir().join(format!("aoc-watch-dir-{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let roundabout = dir.join("..").join(&name);
+    let mut watcher = Watcher::new(days::get(9).unwrap(), vec![roundabout], known);
+
+    let report = watcher.poll().unwrap();
+    fs::remove_file(&path).unwrap();
+    fs::remove_dir(&dir).unwrap();
+    assert!(row(&report, "1").ends_with(&["ok"]), "{}", report);
+}
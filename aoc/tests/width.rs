@@ -0,0 +1,92 @@
+use aoc::days::{self, DAYS};
+use aoc::run::{self, Job, Options};
+use aoc_common::num::{gcd, lcm};
+use aoc_common::{Accumulator, BigInt, Width};
+use std::fs;
+
+fn run(number: u8, input: &str, part: u8, width: Width) -> Result<String, String> {
+    let job = Job {
+        day: days::get(number).unwrap(),
+        name: "input".to_string(),
+        input: input.to_string(),
+    };
+    let options = Options {
+        width,
+        ..Options::default()
+    };
+    run::run_part(&job, part, &options).map(|record| record.answer)
+}
+
+#[test]
+fn every_width_agrees_on_the_examples() {
+    for day in DAYS.iter() {
+        for fname in day.example_inputs() {
+            let input = fs::read_to_string(&fname).unwrap();
+            for part in [1, 2] {
+                let native = run(day.number, &input, part, Width::Native);
+                for width in Width::ALL {
+                    assert_eq!(
+                        run(day.number, &input, part, width),
+                        native,
+                        "day {} part {} with {} on {}",
+                        day.number,
+                        part,
+                        width,
+                        fname.display()
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn overflow_names_the_day_part_and_line() {
+    let input = "2147483647 2147483647\n1 1\n";
+    assert_eq!(
+        run(9, input, 1, Width::Native).unwrap_err(),
+        "error: day 9 part 1 failed on input: line 2: answer overflowed i32\n"
+    );
+    assert_eq!(run(9, input, 1, Width::U64).unwrap(), "2147483648");
+    assert_eq!(run(9, input, 1, Width::Big).unwrap(), "2147483648");
+}
+
+#[test]
+fn part_2_of_day_6_reads_long_rows_at_a_wider_width() {
+    let input = "Time: 10000000000 10000000000\nDistance: 1 1\n";
+    assert_eq!(
+        run(6, input, 1, Width::Native).unwrap_err(),
+        "error: day 6 part 1 failed on input: line 1: answer overflowed u64\n"
+    );
+    assert_eq!(
+        run(6, input, 2, Width::Native).unwrap_err(),
+        "error: day 6 part 2 failed on input: line 1: times don't join into one number\n"
+    );
+    assert_eq!(
+        run(6, input, 2, Width::U128).unwrap(),
+        "1000000000009999999999"
+    );
+    assert_eq!(
+        run(6, input, 2, Width::Big).unwrap(),
+        "1000000000009999999999"
+    );
+}
+
+#[test]
+fn widths_parse_from_their_names() {
+    for width in Width::ALL {
+        assert_eq!(width.to_string().parse(), Ok(width));
+    }
+    assert!("u16".parse::<Width>().is_err());
+}
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(gcd(12u64, 18), 6);
+    assert_eq!(lcm(&4u32, &6), Some(12));
+    assert_eq!(lcm(&u32::MAX, &2), None);
+
+    let big = BigInt::of(u64::MAX).unwrap();
+    let two = BigInt::of(2).unwrap();
+    assert_eq!(lcm(&big, &two), big.checked_mul(&two));
+}
@@ -0,0 +1,79 @@
+use crate::input::Normalizer;
+use crate::{DynSolution, SolveResult, Width};
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::process;
+
+/// Prints one part's answer, or its error. `false` if it failed.
+fn print_answer(part: u8, answer: SolveResult<String>) -> bool {
+    match answer {
+        Ok(answer) => {
+            println!("part {}: {}", part, answer);
+            true
+        }
+        Err(err) => {
+            eprintln!("error: part {}: {}", part, err);
+            false
+        }
+    }
+}
+
+/// Entry point for a single day's binary, run as `dayN <input> [--explain]`. The
+/// `aoc` runner has the full set of options. Days that can stream their input
+/// read it a line at a time, unless explaining. Anything tidied in the input is
+/// warned about.
+pub fn main(solution: &dyn DynSolution) {
+    let fname = env::args().nth(1).expect("need to pass input as arg");
+    let explain = env::args().skip(2).any(|arg| arg == "--explain");
+    let fail = |err: io::Error| -> ! {
+        eprintln!("error: couldn't read {}: {}", fname, err);
+        process::exit(1);
+    };
+    let file = File::open(&fname).unwrap_or_else(|err| fail(err));
+    let mut reader = Normalizer::new(BufReader::new(file));
+    let warn = |reader: &Normalizer<_>| {
+        for irregularity in reader.irregularities() {
+            eprintln!("warning: {}: {}", fname, irregularity);
+        }
+    };
+
+    let streamed = if explain {
+        None
+    } else {
+        solution.stream(&mut reader, Width::Native)
+    };
+    let ok = match streamed {
+        // Both printed even if part 1 failed
+        Some(Ok([part1, part2])) => {
+            warn(&reader);
+            print_answer(1, part1) & print_answer(2, part2)
+        }
+        Some(Err(err)) => {
+            warn(&reader);
+            eprint!("{}", err.render(&fname));
+            false
+        }
+        None => {
+            let input = io::read_to_string(&mut reader).unwrap_or_else(|err| fail(err));
+            warn(&reader);
+            match solution.parse(&input) {
+                Ok(parsed) => (1..=2).fold(true, |ok, part| {
+                    if explain {
+                        for step in parsed.explain(part) {
+                            println!("  {}", step);
+                        }
+                    }
+                    print_answer(part, parsed.part(part)) && ok
+                }),
+                Err(err) => {
+                    eprint!("{}", err.with_file(&fname).render(&input));
+                    false
+                }
+            }
+        }
+    };
+    if !ok {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,131 @@
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+
+pub type ParseResult<T> = Result<T, ParseError>;
+pub type SolveResult<T> = Result<T, SolveError>;
+
+/// Where and why an input failed to parse. `line` and `column` are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line,
+            column,
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error on `token`, which should be a slice of `text`, the full text of line `line`.
+    /// The column is worked out from where the token sits in the line.
+    pub fn at(line: usize, text: &str, token: &str, message: impl Into<String>) -> Self {
+        let start = text.as_ptr() as usize;
+        let offset = match (token.as_ptr() as usize).checked_sub(start) {
+            Some(offset) if offset <= text.len() && text.is_char_boundary(offset) => offset,
+            _ => text.find(token).unwrap_or(0),
+        };
+        ParseError::new(line, text[..offset].chars().count() + 1, token, message)
+    }
+
+    /// Error pointing just past the end of line `line`, for input that stops too early.
+    pub fn at_end(line: usize, text: &str, message: impl Into<String>) -> Self {
+        ParseError::at(line, text, &text[text.len()..], message)
+    }
+
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Formats the error rustc-style, quoting the offending line from `source` with a
+    /// caret under the bad token.
+    pub fn render(&self, source: &str) -> String {
+        self.render_text(source.lines().nth(self.line.wrapping_sub(1)))
+    }
+
+    /// Like `render`, given only the text of the line the error is on.
+    pub fn render_line(&self, text: &str) -> String {
+        self.render_text(Some(text))
+    }
+
+    fn render_text(&self, text: Option<&str>) -> String {
+        let line_no = self.line.to_string();
+        let gutter = " ".repeat(line_no.len());
+        let location = match &self.file {
+            Some(file) => format!("{}:{}:{}", file.display(), self.line, self.column),
+            None => format!("{}:{}", self.line, self.column),
+        };
+
+        let mut out = format!("error: {}\n{}--> {}\n", self.message, gutter, location);
+        if let Some(text) = text {
+            let indent = " ".repeat(self.column.saturating_sub(1));
+            let carets = "^".repeat(self.token.chars().count().max(1));
+            out += &format!("{} |\n", gutter);
+            out += &format!("{} | {}\n", line_no, text);
+            out += &format!("{} | {}{}\n", gutter, indent, carets);
+        }
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        if !self.token.is_empty() {
+            write!(f, " (`{}`)", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Why a part couldn't give an answer for input that parsed fine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    /// 1-based line the problem comes from, when it comes from one line
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError {
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn overflow() -> Self {
+        SolveError::new("answer overflowed")
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for SolveError {}
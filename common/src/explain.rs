@@ -0,0 +1,43 @@
+use crate::SolveError;
+use std::fmt;
+
+/// One piece of intermediate state behind an answer, shown by `--explain`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// 1-based line of the input the step is about, if it's about one
+    pub line: Option<usize>,
+    pub text: String,
+}
+
+impl Step {
+    pub fn new(text: impl Into<String>) -> Self {
+        Step {
+            line: None,
+            text: text.into(),
+        }
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        f.write_str(&self.text)
+    }
+}
+
+/// Lets an explanation end with the error that stopped its part.
+impl From<SolveError> for Step {
+    fn from(err: SolveError) -> Self {
+        Step {
+            line: err.line,
+            text: err.message,
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Tidies puzzle inputs that have been through other editors and machines, so
+//! every day sees the same plain `\n` separated lines. Everything tidied is
+//! tallied, as it can also be a sign the input was damaged on the way.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, BufRead, Read};
+
+const BOM: char = '\u{feff}';
+
+/// How many lines something was found on, and the first of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lines {
+    pub count: usize,
+    pub first: usize,
+}
+
+impl fmt::Display for Lines {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.count == 1 {
+            write!(f, "line {}", self.first)
+        } else {
+            write!(f, "{} lines, from line {}", self.count, self.first)
+        }
+    }
+}
+
+/// Something about an input's layout that was tidied before parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Irregularity {
+    /// A byte order mark before the first line
+    Bom,
+    /// Lines ending in `\r\n`
+    CrLf(Lines),
+    /// Spaces or tabs at the end of a line
+    TrailingWhitespace(Lines),
+    /// Tabs within a line, read as single spaces
+    Tabs(Lines),
+    /// Blank lines after the last line with anything on it, which are dropped
+    TrailingBlankLines(usize),
+}
+
+impl fmt::Display for Irregularity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Irregularity::Bom => write!(f, "removed a byte order mark"),
+            Irregularity::CrLf(lines) => write!(f, "CRLF line endings on {}", lines),
+            Irregularity::TrailingWhitespace(lines) => {
+                write!(f, "trailing whitespace on {}", lines)
+            }
+            Irregularity::Tabs(lines) => write!(f, "tabs read as spaces on {}", lines),
+            Irregularity::TrailingBlankLines(1) => write!(f, "dropped a blank line at the end"),
+            Irregularity::TrailingBlankLines(count) => {
+                write!(f, "dropped {} blank lines at the end", count)
+            }
+        }
+    }
+}
+
+fn note(lines: &mut Option<Lines>, number: usize) {
+    match lines {
+        Some(lines) => lines.count += 1,
+        None => {
+            *lines = Some(Lines {
+                count: 1,
+                first: number,
+            })
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Tally {
+    /// Lines seen so far
+    number: usize,
+    bom: bool,
+    crlf: Option<Lines>,
+    trailing: Option<Lines>,
+    tabs: Option<Lines>,
+}
+
+impl Tally {
+    /// Tidies the next line, given without its `\n`.
+    fn clean<'a>(&mut self, raw: &'a str) -> Cow<'a, str> {
+        self.number += 1;
+        let mut text = raw;
+        if self.number == 1 {
+            if let Some(rest) = text.strip_prefix(BOM) {
+                self.bom = true;
+                text = rest;
+            }
+        }
+        if let Some(rest) = text.strip_suffix('\r') {
+            note(&mut self.crlf, self.number);
+            text = rest;
+        }
+        let trimmed = text.trim_end();
+        if trimmed.len() != text.len() {
+            note(&mut self.trailing, self.number);
+        }
+        if trimmed.contains('\t') {
+            note(&mut self.tabs, self.number);
+            Cow::Owned(trimmed.replace('\t', " "))
+        } else {
+            Cow::Borrowed(trimmed)
+        }
+    }
+
+    fn irregularities(&self, trailing_blank: usize) -> Vec<Irregularity> {
+        let mut found = Vec::new();
+        if self.bom {
+            found.push(Irregularity::Bom);
+        }
+        found.extend(self.crlf.map(Irregularity::CrLf));
+        found.extend(self.trailing.map(Irregularity::TrailingWhitespace));
+        found.extend(self.tabs.map(Irregularity::Tabs));
+        if trailing_blank > 0 {
+            found.push(Irregularity::TrailingBlankLines(trailing_blank));
+        }
+        found
+    }
+}
+
+/// Wraps a reader so it yields tidied lines, reading a line at a time. Every
+/// line comes out ending in `\n`, and line numbers are kept, since only blank
+/// lines at the very end are dropped.
+pub struct Normalizer<R> {
+    inner: R,
+    tally: Tally,
+    raw: String,
+    out: Vec<u8>,
+    pos: usize,
+    /// Blank lines held back until there's something after them
+    blank: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Normalizer<R> {
+    pub fn new(inner: R) -> Self {
+        Normalizer {
+            inner,
+            tally: Tally::default(),
+            raw: String::new(),
+            out: Vec::new(),
+            pos: 0,
+            blank: 0,
+            done: false,
+        }
+    }
+
+    /// What was tidied so far. Trailing blank lines only show up once the input
+    /// has been read to the end.
+    pub fn irregularities(&self) -> Vec<Irregularity> {
+        let trailing_blank = if self.done { self.blank } else { 0 };
+        self.tally.irregularities(trailing_blank)
+    }
+}
+
+impl<R: BufRead> BufRead for Normalizer<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        while self.pos == self.out.len() && !self.done {
+            self.raw.clear();
+            self.out.clear();
+            self.pos = 0;
+            if self.inner.read_line(&mut self.raw)? == 0 {
+                self.done = true;
+                break;
+            }
+
+            let raw = self.raw.strip_suffix('\n').unwrap_or(&self.raw);
+            let line = self.tally.clean(raw);
+            if line.is_empty() {
+                self.blank += 1;
+                continue;
+            }
+            self.out.resize(self.blank, b'\n');
+            self.out.extend_from_slice(line.as_bytes());
+            self.out.push(b'\n');
+            self.blank = 0;
+        }
+        Ok(&self.out[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt;
+    }
+}
+
+impl<R: BufRead> Read for Normalizer<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+/// An input after [`normalize`], with what was tidied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalized {
+    pub text: String,
+    pub irregularities: Vec<Irregularity>,
+}
+
+/// Tidies a whole input at once, the same way [`Normalizer`] does.
+pub fn normalize(raw: &str) -> Normalized {
+    let mut reader = Normalizer::new(raw.as_bytes());
+    let text = io::read_to_string(&mut reader).expect("reading a str can't fail");
+    Normalized {
+        text,
+        irregularities: reader.irregularities(),
+    }
+}
@@ -0,0 +1,20 @@
+mod cli;
+mod error;
+mod explain;
+pub mod input;
+pub mod num;
+pub mod params;
+pub mod parse;
+pub mod query;
+mod solution;
+pub mod stream;
+
+pub use cli::main;
+pub use error::{ParseError, ParseResult, SolveError, SolveResult};
+pub use explain::Step;
+pub use num::{Accumulator, Width};
+pub use num_bigint::BigInt;
+pub use params::Param;
+pub use query::Query;
+pub use solution::{DynSolution, Parsed, Solution};
+pub use stream::StreamResult;
@@ -0,0 +1,212 @@
+//! Integer types answers can be accumulated in, and the `--width` that picks
+//! between them. Every operation is checked, so an answer that doesn't fit is an
+//! error rather than a wrapped value.
+
+use crate::{SolveError, SolveResult, StreamResult};
+use num_bigint::BigInt;
+use std::fmt;
+use std::str::FromStr;
+
+pub trait Accumulator: Clone + PartialOrd + fmt::Display {
+    /// Shown in overflow errors, so they say what didn't fit
+    const NAME: &'static str;
+
+    /// `value` as this type, if it fits.
+    fn from_i128(value: i128) -> Option<Self>;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    /// `None` for a zero divisor, as well as on overflow.
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+    /// `None` for a zero divisor, as well as on overflow.
+    fn checked_rem(&self, other: &Self) -> Option<Self>;
+
+    fn of(value: impl Into<i128>) -> Option<Self> {
+        Self::from_i128(value.into())
+    }
+
+    fn zero() -> Self {
+        Self::from_i128(0).unwrap()
+    }
+
+    fn one() -> Self {
+        Self::from_i128(1).unwrap()
+    }
+
+    fn overflow() -> SolveError {
+        SolveError::new(format!("answer overflowed {}", Self::NAME))
+    }
+}
+
+macro_rules! primitive {
+    ($($t:ty),*) => {$(
+        impl Accumulator for $t {
+            const NAME: &'static str = stringify!($t);
+
+            fn from_i128(value: i128) -> Option<Self> {
+                <$t>::try_from(value).ok()
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *other)
+            }
+
+            fn checked_sub(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_sub(*self, *other)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *other)
+            }
+
+            fn checked_div(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_div(*self, *other)
+            }
+
+            fn checked_rem(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_rem(*self, *other)
+            }
+        }
+    )*};
+}
+
+primitive!(u32, u64, u128, i32, i64, i128);
+
+impl Accumulator for BigInt {
+    const NAME: &'static str = "big";
+
+    fn from_i128(value: i128) -> Option<Self> {
+        Some(BigInt::from(value))
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(self - other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        (*other != BigInt::ZERO).then(|| self / other)
+    }
+
+    fn checked_rem(&self, other: &Self) -> Option<Self> {
+        (*other != BigInt::ZERO).then(|| self % other)
+    }
+}
+
+/// Which accumulator type to work answers out in. Days with signed answers use
+/// the signed type of the same width. Only the arithmetic on answers changes:
+/// the puzzle's own numbers are still read as each day's usual type, so a value
+/// too big for that is a parse error at any width.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Width {
+    /// Whatever the day normally uses
+    #[default]
+    Native,
+    U64,
+    U128,
+    /// Arbitrary precision, which never overflows
+    Big,
+}
+
+impl Width {
+    pub const ALL: [Width; 4] = [Width::Native, Width::U64, Width::U128, Width::Big];
+}
+
+impl fmt::Display for Width {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Width::Native => "native",
+            Width::U64 => "u64",
+            Width::U128 => "u128",
+            Width::Big => "big",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Width {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Width::ALL
+            .into_iter()
+            .find(|width| width.to_string() == name)
+            .ok_or_else(|| format!("expected one of native, u64, u128 or big, not `{}`", name))
+    }
+}
+
+/// Greatest common divisor, by Euclid's algorithm.
+pub fn gcd<N: Accumulator>(a: N, b: N) -> N {
+    match a.checked_rem(&b) {
+        Some(rem) => gcd(b, rem),
+        None => a,
+    }
+}
+
+/// Lowest common multiple, or `None` if it overflows.
+pub fn lcm<N: Accumulator>(a: &N, b: &N) -> Option<N> {
+    a.checked_div(&gcd(a.clone(), b.clone()))?.checked_mul(b)
+}
+
+/// What `with_width!` bodies give back, turned into text once `N` is no longer
+/// needed.
+pub trait ToAnswer<N> {
+    type Text;
+    fn to_answer(self) -> Self::Text;
+}
+
+impl<N: fmt::Display> ToAnswer<N> for SolveResult<N> {
+    type Text = SolveResult<String>;
+
+    fn to_answer(self) -> Self::Text {
+        self.map(|answer| answer.to_string())
+    }
+}
+
+impl<N: fmt::Display> ToAnswer<N> for StreamResult<N> {
+    type Text = StreamResult;
+
+    fn to_answer(self) -> Self::Text {
+        self.map(|parts| parts.map(ToAnswer::to_answer))
+    }
+}
+
+/// Evaluates `$body` with the type `$n` set to the accumulator `$width` picks, and
+/// turns the answer into a string. `$native` is the type for `Width::Native`; put
+/// `signed` before it for days whose answers can be negative.
+#[macro_export]
+macro_rules! with_width {
+    ($width:expr, signed $native:ty, |$n:ident| $body:expr) => {
+        $crate::with_width!(@ $width, $native, i64, i128, |$n| $body)
+    };
+    ($width:expr, $native:ty, |$n:ident| $body:expr) => {
+        $crate::with_width!(@ $width, $native, u64, u128, |$n| $body)
+    };
+    (@ $width:expr, $native:ty, $w64:ty, $w128:ty, |$n:ident| $body:expr) => {
+        match $width {
+            $crate::Width::Native => {
+                type $n = $native;
+                $crate::num::ToAnswer::<$n>::to_answer($body)
+            }
+            $crate::Width::U64 => {
+                type $n = $w64;
+                $crate::num::ToAnswer::<$n>::to_answer($body)
+            }
+            $crate::Width::U128 => {
+                type $n = $w128;
+                $crate::num::ToAnswer::<$n>::to_answer($body)
+            }
+            $crate::Width::Big => {
+                type $n = $crate::BigInt;
+                $crate::num::ToAnswer::<$n>::to_answer($body)
+            }
+        }
+    };
+}
@@ -0,0 +1,59 @@
+//! Puzzle constants a day lets users change, so "what if" questions don't need a
+//! code change. Each day lists its own in [`Solution::params`] and parses them in
+//! [`Solution::set_param`].
+//!
+//! [`Solution::params`]: crate::Solution::params
+//! [`Solution::set_param`]: crate::Solution::set_param
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// One parameter and the value it currently has.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// Written the same way it's set, so it can be copied into `--param`
+    pub value: String,
+    pub help: &'static str,
+}
+
+impl Param {
+    pub fn new(name: &'static str, value: impl Display, help: &'static str) -> Self {
+        Param {
+            name,
+            value: value.to_string(),
+            help,
+        }
+    }
+}
+
+/// `value` parsed as the type of parameter `name`.
+pub fn parse<T>(name: &str, value: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .trim()
+        .parse()
+        .map_err(|err| format!("invalid value `{}` for `{}`: {}", value, name, err))
+}
+
+/// A comma separated list, like `one,two,three`, with exactly `N` entries.
+pub fn parse_list<const N: usize>(name: &str, value: &str) -> Result<[String; N], String> {
+    let items: Vec<String> = value
+        .split(',')
+        .map(|item| item.trim().to_string())
+        .collect();
+    if items.iter().any(String::is_empty) {
+        return Err(format!("empty entry in `{}`", name));
+    }
+    let found = items.len();
+    items
+        .try_into()
+        .map_err(|_| format!("`{}` needs {} entries, found {}", name, N, found))
+}
+
+pub fn unknown(name: &str) -> String {
+    format!("no parameter `{}`", name)
+}
@@ -0,0 +1,128 @@
+//! Helpers for the line-based formats the puzzles use. Everything that reads a
+//! token returns a `ParseError` pointing at it rather than skipping it.
+
+use crate::{ParseError, ParseResult};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// One line of input along with its 1-based line number. The `text` arguments
+/// taken by its methods should be slices of `self.text`, so errors can point at
+/// the right column.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(row, text)| Line {
+        number: row + 1,
+        text,
+    })
+}
+
+/// Groups of consecutive non-blank lines, split wherever there are blank lines.
+pub fn blocks(input: &str) -> impl Iterator<Item = Vec<Line<'_>>> {
+    let mut lines = lines(input).peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|line| line.text.trim().is_empty()).is_some() {}
+        let mut block = Vec::new();
+        while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty()) {
+            block.push(line);
+        }
+        if block.is_empty() {
+            None
+        } else {
+            Some(block)
+        }
+    })
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.number, self.text, token, message)
+    }
+
+    /// Error just past the end of `text`, for something missing after it.
+    pub fn error_after(&self, text: &str, message: impl Into<String>) -> ParseError {
+        self.error(&text[text.len()..], message)
+    }
+
+    pub fn parse<T>(&self, token: &str) -> ParseResult<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        if token.is_empty() {
+            return Err(self.error(token, "expected a number"));
+        }
+        token
+            .parse()
+            .map_err(|err| self.error(token, format!("invalid number: {}", err)))
+    }
+
+    /// Splits `text` around the first `separator`.
+    pub fn split_once(&self, text: &'a str, separator: &str) -> ParseResult<(&'a str, &'a str)> {
+        text.split_once(separator)
+            .ok_or_else(|| self.error_after(text, format!("expected `{}`", separator)))
+    }
+
+    /// Splits a `key: value` line, trimming both sides.
+    pub fn key_value(&self) -> ParseResult<(&'a str, &'a str)> {
+        let (key, value) = self.split_once(self.text, ":")?;
+        Ok((key.trim(), value.trim()))
+    }
+
+    /// Every whitespace separated number in `text`.
+    pub fn numbers<T>(&self, text: &'a str) -> ParseResult<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        text.split_whitespace()
+            .map(|token| self.parse(token))
+            .collect()
+    }
+
+    /// The numbers in a `label: 1 2 3` line.
+    pub fn labelled<T>(&self, label: &str) -> ParseResult<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let (key, value) = self.key_value()?;
+        if key != label {
+            return Err(self.error(key, format!("expected `{}:`", label)));
+        }
+        self.numbers(value)
+    }
+
+    /// Exactly `N` whitespace separated fields from `text`.
+    pub fn fields<const N: usize>(&self, text: &'a str) -> ParseResult<[&'a str; N]> {
+        let mut tokens = text.split_whitespace();
+        let fields: Vec<&str> = tokens.by_ref().take(N).collect();
+        if let Some(extra) = tokens.next() {
+            return Err(self.error(extra, format!("expected only {} fields", N)));
+        }
+        fields.try_into().map_err(|fields: Vec<&str>| {
+            let message = format!("expected {} fields, found {}", N, fields.len());
+            self.error_after(text.trim_end(), message)
+        })
+    }
+
+    /// Exactly `N` whitespace separated numbers from `text`.
+    pub fn record<T, const N: usize>(&self, text: &'a str) -> ParseResult<[T; N]>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let values = self
+            .fields::<N>(text)?
+            .iter()
+            .map(|field| self.parse(field))
+            .collect::<ParseResult<Vec<T>>>()?;
+        Ok(values
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("fields() returned {} fields", N)))
+    }
+}
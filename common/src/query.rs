@@ -0,0 +1,35 @@
+//! Questions about a day's parsed input that `aoc repl` can ask, so looking into
+//! one seed or hand doesn't mean editing a `main`. Each day lists its own in
+//! [`Solution::queries`] and answers them in [`Solution::query`].
+//!
+//! [`Solution::queries`]: crate::Solution::queries
+//! [`Solution::query`]: crate::Solution::query
+
+/// One query and how to ask it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    pub name: &'static str,
+    /// What goes after the name, like `<seed>`
+    pub args: &'static str,
+    pub help: &'static str,
+}
+
+impl Query {
+    pub fn new(name: &'static str, args: &'static str, help: &'static str) -> Self {
+        Query { name, args, help }
+    }
+}
+
+/// Exactly `N` arguments for query `name`, which takes `usage`.
+pub fn args<'a, const N: usize>(
+    name: &str,
+    usage: &str,
+    args: &[&'a str],
+) -> Result<[&'a str; N], String> {
+    args.try_into()
+        .map_err(|_| format!("usage: {} {}", name, usage))
+}
+
+pub fn unknown(name: &str) -> String {
+    format!("no query `{}`", name)
+}
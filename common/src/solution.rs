@@ -0,0 +1,145 @@
+use crate::params::{self, Param};
+use crate::query::{self, Query};
+use crate::{ParseResult, SolveResult, Step, StreamResult, Width};
+use std::fmt::Display;
+use std::io::BufRead;
+
+/// A day's puzzle, split into a parse stage and the two parts that share its output.
+pub trait Solution {
+    type Input;
+    type Answer: Display;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> SolveResult<Self::Answer>;
+    fn part2(&self, input: &Self::Input) -> SolveResult<Self::Answer>;
+
+    /// `part` worked out with the accumulator type `width` picks. Days whose
+    /// answers always fit their native type keep the default, which ignores it.
+    fn part_with(&self, input: &Self::Input, part: u8, _width: Width) -> SolveResult<String> {
+        let answer = match part {
+            1 => self.part1(input)?,
+            2 => self.part2(input)?,
+            _ => panic!("no part {}", part),
+        };
+        Ok(answer.to_string())
+    }
+
+    /// Intermediate state behind `part`'s answer. This is only called for
+    /// `--explain`, so the parts themselves never pay for it. Days with nothing to
+    /// show keep the empty default.
+    fn explain(&self, _input: &Self::Input, _part: u8) -> Vec<Step> {
+        Vec::new()
+    }
+
+    /// Both parts worked out in one pass over `reader`, a line at a time, so the
+    /// whole input is never in memory. Days whose parts need all of the input at
+    /// once keep the default `None`, and nothing is read.
+    fn stream(&self, _reader: &mut dyn BufRead, _width: Width) -> Option<StreamResult> {
+        None
+    }
+
+    /// Every puzzle constant this day can be run with, and its current value.
+    fn params(&self) -> Vec<Param> {
+        Vec::new()
+    }
+
+    /// Sets parameter `name` from its text form, as listed by `params`.
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<(), String> {
+        Err(params::unknown(name))
+    }
+
+    /// Everything `aoc repl` can ask about this day's parsed input besides the
+    /// answers themselves.
+    fn queries(&self) -> Vec<Query> {
+        Vec::new()
+    }
+
+    /// Answers query `name`, as listed by `queries`, given the words after it.
+    fn query(&self, _input: &Self::Input, name: &str, _args: &[&str]) -> Result<String, String> {
+        Err(query::unknown(name))
+    }
+}
+
+/// Parsed input bound to the solution that produced it, with the answer type erased.
+pub trait Parsed {
+    fn part1(&self) -> SolveResult<String>;
+    fn part2(&self) -> SolveResult<String>;
+    fn part_with(&self, part: u8, width: Width) -> SolveResult<String>;
+    fn explain(&self, part: u8) -> Vec<Step>;
+    fn query(&self, name: &str, args: &[&str]) -> Result<String, String>;
+
+    fn part(&self, part: u8) -> SolveResult<String> {
+        match part {
+            1 => self.part1(),
+            2 => self.part2(),
+            _ => panic!("no part {}", part),
+        }
+    }
+}
+
+/// Object safe view of a `Solution` so different days can live in one table.
+pub trait DynSolution: Sync {
+    fn parse<'a>(&'a self, input: &str) -> ParseResult<Box<dyn Parsed + 'a>>;
+    fn stream(&self, reader: &mut dyn BufRead, width: Width) -> Option<StreamResult>;
+    fn params(&self) -> Vec<Param>;
+    fn queries(&self) -> Vec<Query>;
+
+    /// A copy of this solution with each `(name, value)` parameter set in turn.
+    fn with_params(&self, values: &[(String, String)]) -> Result<Box<dyn DynSolution>, String>;
+}
+
+struct ParsedInput<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn part1(&self) -> SolveResult<String> {
+        Ok(self.solution.part1(&self.input)?.to_string())
+    }
+
+    fn part2(&self) -> SolveResult<String> {
+        Ok(self.solution.part2(&self.input)?.to_string())
+    }
+
+    fn part_with(&self, part: u8, width: Width) -> SolveResult<String> {
+        self.solution.part_with(&self.input, part, width)
+    }
+
+    fn explain(&self, part: u8) -> Vec<Step> {
+        self.solution.explain(&self.input, part)
+    }
+
+    fn query(&self, name: &str, args: &[&str]) -> Result<String, String> {
+        self.solution.query(&self.input, name, args)
+    }
+}
+
+impl<S: Solution + Clone + Sync + 'static> DynSolution for S {
+    fn parse<'a>(&'a self, input: &str) -> ParseResult<Box<dyn Parsed + 'a>> {
+        Ok(Box::new(ParsedInput {
+            solution: self,
+            input: Solution::parse(self, input)?,
+        }))
+    }
+
+    fn stream(&self, reader: &mut dyn BufRead, width: Width) -> Option<StreamResult> {
+        Solution::stream(self, reader, width)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        Solution::params(self)
+    }
+
+    fn queries(&self) -> Vec<Query> {
+        Solution::queries(self)
+    }
+
+    fn with_params(&self, values: &[(String, String)]) -> Result<Box<dyn DynSolution>, String> {
+        let mut solution = self.clone();
+        for (name, value) in values {
+            solution.set_param(name, value)?;
+        }
+        Ok(Box::new(solution))
+    }
+}
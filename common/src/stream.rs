@@ -0,0 +1,86 @@
+//! Reading input a line at a time, for days whose answers build up line by line.
+//! Only the current line is ever held, so inputs can be bigger than memory and
+//! come from pipes.
+
+use crate::parse::Line;
+use crate::{Accumulator, ParseError, ParseResult, SolveResult};
+use std::fmt;
+use std::io::{self, BufRead};
+
+#[derive(Debug)]
+pub enum StreamError {
+    Read(io::Error),
+    /// A line that didn't parse, along with its text since the input can't be
+    /// looked at again to show it
+    Parse {
+        error: ParseError,
+        text: String,
+    },
+}
+
+impl StreamError {
+    /// Formats the error like [`ParseError::render`], naming `file`.
+    pub fn render(self, file: &str) -> String {
+        match self {
+            StreamError::Read(err) => format!("error: couldn't read {}: {}\n", file, err),
+            StreamError::Parse { error, text } => error.with_file(file).render_line(&text),
+        }
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Read(err) => write!(f, "couldn't read input: {}", err),
+            StreamError::Parse { error, .. } => error.fmt(f),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Read(err)
+    }
+}
+
+/// Both parts' answers from one pass over the input, or why the pass stopped.
+pub type StreamResult<N = String> = Result<[SolveResult<N>; 2], StreamError>;
+
+/// Calls `f` with each line of `reader`, without its line ending. One buffer is
+/// reused for every line.
+pub fn for_each_line(
+    reader: &mut dyn BufRead,
+    mut f: impl FnMut(Line) -> ParseResult<()>,
+) -> Result<(), StreamError> {
+    let mut buffer = String::new();
+    let mut number = 0;
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            return Ok(());
+        }
+        number += 1;
+
+        let text = buffer.strip_suffix('\n').unwrap_or(&buffer);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        f(Line { number, text }).map_err(|error| StreamError::Parse {
+            error,
+            text: text.to_string(),
+        })?;
+    }
+}
+
+/// Adds `value()` to a running total, with overflow reported on `line`. Once a
+/// total has failed it stays failed and `value` isn't worked out any more.
+pub fn add<N: Accumulator>(
+    total: &mut SolveResult<N>,
+    line: usize,
+    value: impl FnOnce() -> SolveResult<N>,
+) {
+    if let Ok(sum) = total {
+        *total = value().and_then(|value| {
+            sum.checked_add(&value)
+                .ok_or_else(|| N::overflow().on_line(line))
+        });
+    }
+}
@@ -0,0 +1,227 @@
+//! Day 1: Trebuchet?! Sums the calibration value hidden in each line, made of its
+//! first and last digits.
+
+use aoc_common::params::{self, Param};
+use aoc_common::stream;
+use aoc_common::{
+    with_width, Accumulator, ParseResult, Solution, SolveError, SolveResult, Step, StreamResult,
+    Width,
+};
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::io::BufRead;
+
+/// The spelled out digits part 2 looks for, one to nine.
+pub const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// First and last numeral in `line`.
+pub fn numeral_digits(line: &str) -> Option<(u32, u32)> {
+    let mut numbers = line.chars().filter_map(|c| c.to_digit(10));
+    let first = numbers.next()?;
+    Some((first, numbers.next_back().unwrap_or(first)))
+}
+
+/// Finds digits written either way. Spelled out digits can overlap, like the
+/// `eightwo` in `eightwothree`, so each numeral and word is looked for on its own:
+/// from the start for the first digit, and from the end for the last.
+struct DigitFinder<'a> {
+    words: &'a [Cow<'static, str>],
+}
+
+impl<'a> DigitFinder<'a> {
+    fn new(words: &'a [Cow<'static, str>]) -> Self {
+        DigitFinder { words }
+    }
+
+    /// Where `find` finds a numeral and each of the words in `line`, with the
+    /// digit's value. The numeral comes first, then the words in order.
+    fn found<'b>(
+        &'b self,
+        line: &'b str,
+        numeral: Option<usize>,
+        find: fn(&str, &str) -> Option<usize>,
+    ) -> impl Iterator<Item = (usize, u32)> + 'b {
+        let numeral = numeral.map(|i| (i, u32::from(line.as_bytes()[i] - b'0')));
+        let words = self
+            .words
+            .iter()
+            .zip(1..)
+            .filter_map(move |(word, value)| Some((find(line, word)?, value)));
+        numeral.into_iter().chain(words)
+    }
+
+    /// First and last digit in `line`, numerals or spelled out. Of two starting
+    /// at the same place, the numeral or the earlier word wins.
+    fn digits(&self, line: &str) -> Option<(u32, u32)> {
+        let first = line.find(|c: char| c.is_ascii_digit());
+        let (_, first) = self
+            .found(line, first, |line, word| line.find(word))
+            .min_by_key(|&(at, _)| at)?;
+        let last = line.rfind(|c: char| c.is_ascii_digit());
+        let (_, last) = self
+            .found(line, last, |line, word| line.rfind(word))
+            .min_by_key(|&(at, _)| Reverse(at))?;
+        Some((first, last))
+    }
+}
+
+const NO_NUMERALS: &str = "no digits";
+const NO_DIGITS: &str = "no digits or spelled out digits";
+
+/// The calibration value for line `line`, given its first and last digits.
+fn calibration<N: Accumulator>(
+    digits: Option<(u32, u32)>,
+    missing: &str,
+    line: usize,
+) -> SolveResult<N> {
+    let (first, last) = digits.ok_or_else(|| SolveError::new(missing).on_line(line))?;
+    N::of(first * 10 + last).ok_or_else(|| N::overflow().on_line(line))
+}
+
+fn sum_calibrations<N: Accumulator>(
+    lines: &[String],
+    mut digits: impl FnMut(&str) -> Option<(u32, u32)>,
+    missing: &str,
+) -> SolveResult<N> {
+    lines
+        .iter()
+        .enumerate()
+        .try_fold(N::zero(), |acc, (i, line)| {
+            acc.checked_add(&calibration(digits(line), missing, i + 1)?)
+                .ok_or_else(|| N::overflow().on_line(i + 1))
+        })
+}
+
+fn sum_part<N: Accumulator>(
+    lines: &[String],
+    part: u8,
+    words: &[Cow<'static, str>],
+) -> SolveResult<N> {
+    if part == 1 {
+        return sum_calibrations(lines, numeral_digits, NO_NUMERALS);
+    }
+    let finder = DigitFinder::new(words);
+    sum_calibrations(lines, |line| finder.digits(line), NO_DIGITS)
+}
+
+/// Both parts' sums from one pass over `reader`.
+fn stream_sums<N: Accumulator>(
+    reader: &mut dyn BufRead,
+    words: &[Cow<'static, str>],
+) -> StreamResult<N> {
+    let finder = DigitFinder::new(words);
+    let mut sums = [Ok(N::zero()), Ok(N::zero())];
+    stream::for_each_line(reader, |line| {
+        let [numerals, digits] = &mut sums;
+        stream::add(numerals, line.number, || {
+            calibration(numeral_digits(line.text), NO_NUMERALS, line.number)
+        });
+        stream::add(digits, line.number, || {
+            calibration(finder.digits(line.text), NO_DIGITS, line.number)
+        });
+        Ok(())
+    })?;
+    Ok(sums)
+}
+
+/// Parsing keeps the raw lines, since the two parts look for digits differently.
+#[derive(Clone)]
+pub struct Day1 {
+    /// The words for one to nine, set with the `words` parameter
+    pub words: [Cow<'static, str>; 9],
+}
+
+impl Day1 {
+    pub const DEFAULT: Day1 = Day1 {
+        words: [
+            Cow::Borrowed(WORDS[0]),
+            Cow::Borrowed(WORDS[1]),
+            Cow::Borrowed(WORDS[2]),
+            Cow::Borrowed(WORDS[3]),
+            Cow::Borrowed(WORDS[4]),
+            Cow::Borrowed(WORDS[5]),
+            Cow::Borrowed(WORDS[6]),
+            Cow::Borrowed(WORDS[7]),
+            Cow::Borrowed(WORDS[8]),
+        ],
+    };
+}
+
+impl Default for Day1 {
+    fn default() -> Self {
+        Day1::DEFAULT
+    }
+}
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+    type Answer = u32;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, lines: &Self::Input) -> SolveResult<u32> {
+        sum_part(lines, 1, &self.words)
+    }
+
+    fn part2(&self, lines: &Self::Input) -> SolveResult<u32> {
+        sum_part(lines, 2, &self.words)
+    }
+
+    fn part_with(&self, lines: &Self::Input, part: u8, width: Width) -> SolveResult<String> {
+        with_width!(width, u32, |N| sum_part::<N>(lines, part, &self.words))
+    }
+
+    fn stream(&self, reader: &mut dyn BufRead, width: Width) -> Option<StreamResult> {
+        Some(with_width!(width, u32, |N| stream_sums::<N>(
+            reader,
+            &self.words
+        )))
+    }
+
+    /// The first and last digit matched on each line.
+    fn explain(&self, lines: &Self::Input, part: u8) -> Vec<Step> {
+        let finder = DigitFinder::new(&self.words);
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let digits = match part {
+                    1 => numeral_digits(line),
+                    _ => finder.digits(line),
+                };
+                let text = match digits {
+                    Some((first, last)) => format!("{}: {} and {}", line, first, last),
+                    None => format!("{}: no digits", line),
+                };
+                Step::new(text).on_line(i + 1)
+            })
+            .collect()
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param::new(
+            "words",
+            self.words.join(","),
+            "the spelled out digits one to nine, comma separated",
+        )]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        if name != "words" {
+            return Err(params::unknown(name));
+        }
+        let words = params::parse_list::<9>(name, value)?;
+        if let Some(word) = words
+            .iter()
+            .find(|word| word.contains(|c: char| c.is_ascii_digit()))
+        {
+            return Err(format!("`{}` can't contain a numeral", word));
+        }
+        self.words = words.map(Cow::Owned);
+        Ok(())
+    }
+}
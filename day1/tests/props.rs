@@ -0,0 +1,42 @@
+use aoc_common::Solution;
+use aoc_gen::day1::WORDS;
+use day1::Day1;
+use proptest::prelude::*;
+
+/// Digits found by checking every position of the line in turn, so overlapping
+/// words like `eightwo` give both digits.
+fn digits(line: &str, spelled: bool) -> Vec<u32> {
+    (0..line.len())
+        .filter_map(|i| {
+            let rest = &line[i..];
+            let numeral = rest.chars().next().and_then(|c| c.to_digit(10));
+            let word = || {
+                WORDS
+                    .iter()
+                    .position(|word| rest.starts_with(word))
+                    .map(|i| i as u32 + 1)
+            };
+            numeral.or_else(|| if spelled { word() } else { None })
+        })
+        .collect()
+}
+
+fn calibration(digits: &[u32]) -> u32 {
+    digits[0] * 10 + digits[digits.len() - 1]
+}
+
+proptest! {
+    #[test]
+    fn matches_checking_every_position(input in aoc_gen::day1::input(1..=20)) {
+        let lines = Day1::DEFAULT.parse(&input).unwrap();
+        let sum = |spelled| input.lines().map(|line| calibration(&digits(line, spelled))).sum::<u32>();
+        prop_assert_eq!(Day1::DEFAULT.part1(&lines), Ok(sum(false)));
+        prop_assert_eq!(Day1::DEFAULT.part2(&lines), Ok(sum(true)));
+    }
+
+    #[test]
+    fn part1_ignores_letters(line in aoc_gen::day1::line()) {
+        let numerals: String = line.chars().filter(char::is_ascii_digit).collect();
+        prop_assert_eq!(Day1::DEFAULT.part1(&vec![line]), Day1::DEFAULT.part1(&vec![numerals]));
+    }
+}
@@ -0,0 +1,263 @@
+//! Day 2: Cube Conundrum. Checks which games are possible with a limited bag of
+//! cubes, and the smallest bag each game needs.
+
+use aoc_common::params::{self, Param};
+use aoc_common::parse::{self, Line};
+use aoc_common::query::{self, Query};
+use aoc_common::stream;
+use aoc_common::{
+    with_width, Accumulator, ParseResult, Solution, SolveResult, Step, StreamResult, Width,
+};
+use std::cmp;
+use std::io::BufRead;
+
+/// Red, green and blue cubes shown in one round of a game.
+pub type Round = (u32, u32, u32);
+
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+/// Cube counts for a round like `3 blue, 4 red`, which is a slice of `line`.
+pub fn get_cube_counts(line: Line, round: &str) -> ParseResult<Round> {
+    let mut acc: Round = (0, 0, 0);
+    for draw in round.split(',') {
+        let [count, colour] = line.fields::<2>(draw)?;
+        let total = match colour {
+            "red" => &mut acc.0,
+            "green" => &mut acc.1,
+            "blue" => &mut acc.2,
+            _ => return Err(line.error(colour, "unrecognised colour")),
+        };
+        *total = total
+            .checked_add(line.parse(count)?)
+            .ok_or_else(|| line.error(count, "too many cubes"))?;
+    }
+    Ok(acc)
+}
+
+/// Parses a `Game 1: 3 blue, 4 red; 1 red, 2 green` line.
+pub fn parse_game(line: Line) -> ParseResult<Game> {
+    let (label, rounds) = line.key_value()?;
+    let [_, id] = line.fields::<2>(label)?;
+    let id = line.parse(id)?;
+    let rounds = rounds
+        .split(';')
+        .map(|round| get_cube_counts(line, round))
+        .collect::<ParseResult<_>>()?;
+    Ok(Game { id, rounds })
+}
+
+/// The red, green and blue cubes in the bag part 1 asks about.
+pub const BAG: Round = (12, 13, 14);
+
+/// The first round of `game` that shows more cubes of some colour than `bag`
+/// holds, as the round's index, the colour and how many were shown.
+pub fn broken_limit(game: &Game, bag: Round) -> Option<(usize, &'static str, u32)> {
+    game.rounds
+        .iter()
+        .enumerate()
+        .find_map(|(i, &(red, green, blue))| {
+            [
+                ("red", red, bag.0),
+                ("green", green, bag.1),
+                ("blue", blue, bag.2),
+            ]
+            .into_iter()
+            .find(|&(_, count, max)| count > max)
+            .map(|(colour, count, _)| (i, colour, count))
+        })
+}
+
+/// The fewest red, green and blue cubes that make `game` possible.
+pub fn min_bag(game: &Game) -> Round {
+    game.rounds
+        .iter()
+        .fold((0, 0, 0), |acc, &(red, green, blue)| {
+            (
+                cmp::max(acc.0, red),
+                cmp::max(acc.1, green),
+                cmp::max(acc.2, blue),
+            )
+        })
+}
+
+/// `game`'s ID if it's possible with `bag`, otherwise zero. `line` is where the
+/// game is, for errors.
+fn possible_id<N: Accumulator>(game: &Game, bag: Round, line: usize) -> SolveResult<N> {
+    if broken_limit(game, bag).is_some() {
+        return Ok(N::zero());
+    }
+    N::of(game.id).ok_or_else(|| N::overflow().on_line(line))
+}
+
+/// The power of `game`'s smallest bag: its red, green and blue cubes multiplied
+/// together.
+fn power<N: Accumulator>(game: &Game, line: usize) -> SolveResult<N> {
+    let (red, green, blue) = min_bag(game);
+    [red, green, blue]
+        .into_iter()
+        .try_fold(N::one(), |power, count| power.checked_mul(&N::of(count)?))
+        .ok_or_else(|| N::overflow().on_line(line))
+}
+
+fn sum_games<N: Accumulator>(
+    games: &[Game],
+    value: impl Fn(&Game, usize) -> SolveResult<N>,
+) -> SolveResult<N> {
+    games
+        .iter()
+        .enumerate()
+        .try_fold(N::zero(), |acc, (i, game)| {
+            acc.checked_add(&value(game, i + 1)?)
+                .ok_or_else(|| N::overflow().on_line(i + 1))
+        })
+}
+
+/// Sum of the IDs of the games possible with `bag`.
+pub fn possible_ids<N: Accumulator>(games: &[Game], bag: Round) -> SolveResult<N> {
+    sum_games(games, |game, line| possible_id(game, bag, line))
+}
+
+/// Sum of the power of each game's smallest bag.
+pub fn total_power<N: Accumulator>(games: &[Game]) -> SolveResult<N> {
+    sum_games(games, power)
+}
+
+/// Both parts' sums from one pass over `reader`, a game at a time.
+fn stream_sums<N: Accumulator>(reader: &mut dyn BufRead, bag: Round) -> StreamResult<N> {
+    let mut sums = [Ok(N::zero()), Ok(N::zero())];
+    stream::for_each_line(reader, |line| {
+        let game = parse_game(line)?;
+        let [ids, powers] = &mut sums;
+        stream::add(ids, line.number, || possible_id(&game, bag, line.number));
+        stream::add(powers, line.number, || power(&game, line.number));
+        Ok(())
+    })?;
+    Ok(sums)
+}
+
+/// Parses each line into a [`Game`].
+#[derive(Clone)]
+pub struct Day2 {
+    /// The bag for part 1, set with the `red`, `green` and `blue` parameters
+    pub bag: Round,
+}
+
+impl Day2 {
+    pub const DEFAULT: Day2 = Day2 { bag: BAG };
+}
+
+impl Default for Day2 {
+    fn default() -> Self {
+        Day2::DEFAULT
+    }
+}
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Input> {
+        parse::lines(input).map(parse_game).collect()
+    }
+
+    fn part1(&self, games: &Self::Input) -> SolveResult<u32> {
+        possible_ids(games, self.bag)
+    }
+
+    fn part2(&self, games: &Self::Input) -> SolveResult<u32> {
+        total_power(games)
+    }
+
+    fn part_with(&self, games: &Self::Input, part: u8, width: Width) -> SolveResult<String> {
+        with_width!(width, u32, |N| match part {
+            1 => possible_ids::<N>(games, self.bag),
+            _ => total_power::<N>(games),
+        })
+    }
+
+    fn stream(&self, reader: &mut dyn BufRead, width: Width) -> Option<StreamResult> {
+        Some(with_width!(width, u32, |N| stream_sums::<N>(
+            reader, self.bag
+        )))
+    }
+
+    /// Part 1 shows the round that ruled out each impossible game, part 2 the
+    /// smallest bag for every game.
+    fn explain(&self, games: &Self::Input, part: u8) -> Vec<Step> {
+        games
+            .iter()
+            .enumerate()
+            .filter_map(|(i, game)| {
+                let text = if part == 1 {
+                    let (round, colour, count) = broken_limit(game, self.bag)?;
+                    format!(
+                        "game {} round {} shows {} {}, more than the bag holds",
+                        game.id,
+                        round + 1,
+                        count,
+                        colour
+                    )
+                } else {
+                    let (red, green, blue) = min_bag(game);
+                    format!(
+                        "game {} needs {} red, {} green and {} blue",
+                        game.id, red, green, blue
+                    )
+                };
+                Some(Step::new(text).on_line(i + 1))
+            })
+            .collect()
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("red", self.bag.0, "red cubes in the bag for part 1"),
+            Param::new("green", self.bag.1, "green cubes in the bag for part 1"),
+            Param::new("blue", self.bag.2, "blue cubes in the bag for part 1"),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let count = match name {
+            "red" => &mut self.bag.0,
+            "green" => &mut self.bag.1,
+            "blue" => &mut self.bag.2,
+            _ => return Err(params::unknown(name)),
+        };
+        *count = params::parse(name, value)?;
+        Ok(())
+    }
+
+    fn queries(&self) -> Vec<Query> {
+        vec![Query::new(
+            "bag",
+            "<game>",
+            "the fewest cubes that make a game possible",
+        )]
+    }
+
+    fn query(&self, games: &Self::Input, name: &str, args: &[&str]) -> Result<String, String> {
+        match name {
+            "bag" => {
+                let [id] = query::args(name, "<game>", args)?;
+                let id: u32 = params::parse("game", id)?;
+                let game = games
+                    .iter()
+                    .find(|game| game.id == id)
+                    .ok_or_else(|| format!("no game {}", id))?;
+                let (red, green, blue) = min_bag(game);
+                Ok(format!(
+                    "{} red, {} green and {} blue, power {}",
+                    red,
+                    green,
+                    blue,
+                    u128::from(red) * u128::from(green) * u128::from(blue)
+                ))
+            }
+            _ => Err(query::unknown(name)),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use aoc_2023_day2::{Day2, Game};
+use aoc_common::Solution;
+use proptest::prelude::*;
+
+fn min_bag(game: &Game) -> (u32, u32, u32) {
+    game.rounds.iter().fold((0, 0, 0), |bag, round| {
+        (bag.0.max(round.0), bag.1.max(round.1), bag.2.max(round.2))
+    })
+}
+
+proptest! {
+    #[test]
+    fn rounds_parse_to_their_counts(round in aoc_gen::day2::round()) {
+        let games = Day2::DEFAULT.parse(&format!("Game 1: {}", round)).unwrap();
+        let mut counts = (0, 0, 0);
+        for draw in round.split(", ") {
+            let (count, colour) = draw.split_once(' ').unwrap();
+            let count: u32 = count.parse().unwrap();
+            match colour {
+                "red" => counts.0 = count,
+                "green" => counts.1 = count,
+                _ => counts.2 = count,
+            }
+        }
+        prop_assert_eq!(&games[0].rounds, &vec![counts]);
+    }
+
+    #[test]
+    fn possible_games_fit_their_minimum_bag(input in aoc_gen::day2::input(1..=20)) {
+        let games = Day2::DEFAULT.parse(&input).unwrap();
+        let possible: u32 = games
+            .iter()
+            .filter(|game| {
+                let (red, green, blue) = min_bag(game);
+                red <= 12 && green <= 13 && blue <= 14
+            })
+            .map(|game| game.id)
+            .sum();
+        let power: u32 = games
+            .iter()
+            .map(|game| {
+                let (red, green, blue) = min_bag(game);
+                red * green * blue
+            })
+            .sum();
+        prop_assert_eq!(Day2::DEFAULT.part1(&games), Ok(possible));
+        prop_assert_eq!(Day2::DEFAULT.part2(&games), Ok(power));
+    }
+}
@@ -0,0 +1,190 @@
+//! Day 3: Gear Ratios. Finds the part numbers next to symbols in an engine
+//! schematic, and the gears with exactly two of them.
+
+use aoc_common::params::{self, Param};
+use aoc_common::{with_width, Accumulator, ParseError, ParseResult, Solution, SolveResult, Width};
+use aoc_grid::{Grid, Pos};
+use std::ops::Range;
+
+/// A number in the schematic and the cells it covers in its row.
+pub struct PartNumber {
+    pub value: u32,
+    pub row: usize,
+    pub cols: Range<usize>,
+}
+
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub numbers: Vec<PartNumber>,
+    /// Index into `numbers` for every cell that holds one of its digits
+    pub number_ids: Grid<Option<usize>>,
+}
+
+/// Anything other than a digit or `.` counts as a symbol.
+pub fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+pub fn parse_schematic(input: &str) -> ParseResult<Schematic> {
+    let grid = Grid::parse(input)?;
+    if grid.height() == 0 {
+        return Err(ParseError::new(1, 1, "", "empty schematic"));
+    }
+
+    let mut numbers = Vec::new();
+    let mut number_ids = Grid::new(grid.width(), grid.height(), None);
+
+    for (row, cells) in grid.rows().enumerate() {
+        let mut col = 0;
+        while col < cells.len() {
+            if !cells[col].is_ascii_digit() {
+                col += 1;
+                continue;
+            }
+
+            let start = col;
+            while col < cells.len() && cells[col].is_ascii_digit() {
+                number_ids[Pos::new(row, col)] = Some(numbers.len());
+                col += 1;
+            }
+
+            let digits: String = cells[start..col].iter().collect();
+            let value: u32 = digits.parse().map_err(|_| {
+                ParseError::new(row + 1, start + 1, &digits, "part number too large")
+            })?;
+            numbers.push(PartNumber {
+                value,
+                row,
+                cols: start..col,
+            });
+        }
+    }
+
+    Ok(Schematic {
+        grid,
+        numbers,
+        number_ids,
+    })
+}
+
+/// Sum of the numbers next to a symbol.
+pub fn part_number_sum<N: Accumulator>(schematic: &Schematic) -> SolveResult<N> {
+    schematic
+        .numbers
+        .iter()
+        .filter(|number| {
+            let top_left = Pos::new(
+                number.row.saturating_sub(1),
+                number.cols.start.saturating_sub(1),
+            );
+            let bottom_right = Pos::new(number.row + 1, number.cols.end);
+            schematic
+                .grid
+                .region(top_left, bottom_right)
+                .any(|(_, &c)| is_symbol(c))
+        })
+        .try_fold(N::zero(), |sum, number| {
+            N::of(number.value)
+                .and_then(|value| sum.checked_add(&value))
+                .ok_or_else(|| N::overflow().on_line(number.row + 1))
+        })
+}
+
+/// Sum of the ratios of every gear: a `symbol` with exactly `parts` numbers next
+/// to it, whose ratio is those numbers multiplied together.
+pub fn gear_ratio_sum<N: Accumulator>(
+    schematic: &Schematic,
+    symbol: char,
+    parts: usize,
+) -> SolveResult<N> {
+    schematic
+        .grid
+        .iter()
+        .filter(|&(_, &cell)| cell == symbol)
+        .try_fold(N::zero(), |sum, (gear, _)| {
+            let mut adjacent_numbers: Vec<usize> = schematic
+                .grid
+                .neighbours8(gear)
+                .filter_map(|pos| schematic.number_ids[pos])
+                .collect();
+            adjacent_numbers.sort();
+            adjacent_numbers.dedup();
+
+            if adjacent_numbers.len() != parts {
+                return Ok(sum);
+            }
+            adjacent_numbers
+                .iter()
+                .try_fold(N::one(), |ratio, &id| {
+                    ratio.checked_mul(&N::of(schematic.numbers[id].value)?)
+                })
+                .and_then(|ratio| sum.checked_add(&ratio))
+                .ok_or_else(|| N::overflow().on_line(gear.row + 1))
+        })
+}
+
+/// Parses the input into a [`Schematic`] grid.
+#[derive(Clone)]
+pub struct Day3 {
+    /// The symbol part 2 treats as a gear
+    pub gear: char,
+    /// How many numbers a gear has to be next to
+    pub gear_parts: usize,
+}
+
+impl Day3 {
+    pub const DEFAULT: Day3 = Day3 {
+        gear: '*',
+        gear_parts: 2,
+    };
+}
+
+impl Default for Day3 {
+    fn default() -> Self {
+        Day3::DEFAULT
+    }
+}
+
+impl Solution for Day3 {
+    type Input = Schematic;
+    type Answer = u32;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Input> {
+        parse_schematic(input)
+    }
+
+    fn part1(&self, schematic: &Self::Input) -> SolveResult<u32> {
+        part_number_sum(schematic)
+    }
+
+    fn part2(&self, schematic: &Self::Input) -> SolveResult<u32> {
+        gear_ratio_sum(schematic, self.gear, self.gear_parts)
+    }
+
+    fn part_with(&self, schematic: &Self::Input, part: u8, width: Width) -> SolveResult<String> {
+        with_width!(width, u32, |N| match part {
+            1 => part_number_sum::<N>(schematic),
+            _ => gear_ratio_sum::<N>(schematic, self.gear, self.gear_parts),
+        })
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("gear", self.gear, "symbol part 2 treats as a gear"),
+            Param::new(
+                "gear_parts",
+                self.gear_parts,
+                "how many numbers a gear has to be next to",
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "gear" => self.gear = params::parse(name, value)?,
+            "gear_parts" => self.gear_parts = params::parse(name, value)?,
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use aoc_2023_day3::{is_symbol, parse_schematic, Day3};
+use aoc_common::Solution;
+use aoc_grid::Pos;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn numbers_cover_their_digits(input in aoc_gen::day3::input(1..=20)) {
+        let schematic = parse_schematic(&input).unwrap();
+        for (id, number) in schematic.numbers.iter().enumerate() {
+            let digits: String = number
+                .cols
+                .clone()
+                .map(|col| schematic.grid[Pos::new(number.row, col)])
+                .collect();
+            prop_assert_eq!(digits, number.value.to_string());
+            for col in number.cols.clone() {
+                prop_assert_eq!(schematic.number_ids[Pos::new(number.row, col)], Some(id));
+            }
+        }
+
+        let digits = input.chars().filter(char::is_ascii_digit).count();
+        let covered: usize = schematic.numbers.iter().map(|number| number.cols.len()).sum();
+        prop_assert_eq!(covered, digits);
+    }
+
+    #[test]
+    fn part_numbers_are_some_of_the_numbers(input in aoc_gen::day3::input(1..=20)) {
+        let schematic = Day3::DEFAULT.parse(&input).unwrap();
+        let total: u32 = schematic.numbers.iter().map(|number| number.value).sum();
+        prop_assert!(Day3::DEFAULT.part1(&schematic).unwrap() <= total);
+    }
+
+    #[test]
+    fn no_symbols_no_parts(input in aoc_gen::day3::input(1..=20)) {
+        let blank: String = input
+            .chars()
+            .map(|c| if c != '\n' && is_symbol(c) { '.' } else { c })
+            .collect();
+        let schematic = Day3::DEFAULT.parse(&blank).unwrap();
+        prop_assert_eq!(Day3::DEFAULT.part1(&schematic), Ok(0));
+        prop_assert_eq!(Day3::DEFAULT.part2(&schematic), Ok(0));
+    }
+}
@@ -0,0 +1,235 @@
+//! Day 4: Scratchcards. Scores each card by its winning numbers, then counts the
+//! copies won by cascading wins down the pile.
+
+use aoc_common::parse::{self, Line};
+use aoc_common::stream;
+use aoc_common::{
+    with_width, Accumulator, BigInt, ParseResult, Solution, SolveError, SolveResult, Step,
+    StreamResult, Width,
+};
+use std::collections::{HashSet, VecDeque};
+use std::io::BufRead;
+
+pub struct Card {
+    pub played: HashSet<u32>,
+    pub winning: HashSet<u32>,
+}
+
+impl Card {
+    /// How many of the played numbers are winning numbers.
+    pub fn score(&self) -> u32 {
+        self.played.intersection(&self.winning).count() as u32
+    }
+}
+
+/// Parses a `Card 1: 41 48 83 | 83 86 6` line.
+pub fn parse_card(line: Line) -> ParseResult<Card> {
+    let (_, card_info) = line.key_value()?;
+    let (played, winning) = line.split_once(card_info, "|")?;
+
+    Ok(Card {
+        played: line.numbers(played)?.into_iter().collect(),
+        winning: line.numbers(winning)?.into_iter().collect(),
+    })
+}
+
+/// What a card with `score` matches is worth: nothing without any, otherwise 1
+/// doubled for every match after the first. `line` is where the card is, for
+/// errors.
+fn worth<N: Accumulator>(score: u32, line: usize) -> SolveResult<N> {
+    if score == 0 {
+        return Ok(N::zero());
+    }
+    let two = N::of(2u8).ok_or_else(|| N::overflow().on_line(line))?;
+    (1..score)
+        .try_fold(N::one(), |worth, _| worth.checked_mul(&two))
+        .ok_or_else(|| N::overflow().on_line(line))
+}
+
+/// Total points of every card.
+pub fn points<N: Accumulator>(cards: &[Card]) -> SolveResult<N> {
+    cards
+        .iter()
+        .map(Card::score)
+        .enumerate()
+        .try_fold(N::zero(), |points, (i, score)| {
+            points
+                .checked_add(&worth(score, i + 1)?)
+                .ok_or_else(|| N::overflow().on_line(i + 1))
+        })
+}
+
+const PAST_END: &str = "wins copies of cards past the end of the table";
+
+/// `reach`, the card that wins copies furthest down the table and the last card
+/// it wins, taken on to card number `card`. The earlier card wins a tie.
+fn furthest(reach: (usize, usize), card: usize, score: u32) -> (usize, usize) {
+    let last = card + score as usize;
+    if last > reach.1 {
+        (card, last)
+    } else {
+        reach
+    }
+}
+
+/// How many copies of each card end up in the pile, counting the original.
+/// Winning past the end of the table is checked for first, on the card that
+/// wins furthest past it, as that's all a stream can tell once it gets there.
+pub fn card_copies<N: Accumulator>(cards: &[Card]) -> SolveResult<Vec<N>> {
+    let (card, last) = (1..).zip(cards).fold((0, 0), |reach, (number, card)| {
+        furthest(reach, number, card.score())
+    });
+    if last > cards.len() {
+        return Err(SolveError::new(PAST_END).on_line(card));
+    }
+
+    let mut copies: Vec<N> = vec![N::one(); cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        for j in i + 1..i + 1 + card.score() as usize {
+            copies[j] = copies[j]
+                .checked_add(&copies[i])
+                .ok_or_else(|| N::overflow().on_line(j + 1))?;
+        }
+    }
+    Ok(copies)
+}
+
+/// How many cards end up in the pile.
+pub fn total_cards<N: Accumulator>(cards: &[Card]) -> SolveResult<N> {
+    card_copies::<N>(cards)?
+        .iter()
+        .enumerate()
+        .try_fold(N::zero(), |total, (i, count)| {
+            total
+                .checked_add(count)
+                .ok_or_else(|| N::overflow().on_line(i + 1))
+        })
+}
+
+/// Part 2 worked out a card at a time, failing the same way `total_cards` would.
+struct CopyCounter<N> {
+    cards: usize,
+    /// Copies of the next few cards, counting the original, that earlier cards
+    /// have won so far
+    pending: VecDeque<N>,
+    /// Fails on the card it overflows on, which only matters if nothing else did
+    total: SolveResult<N>,
+    /// The first overflow counting copies
+    failed: Option<SolveError>,
+    /// As `furthest` gives it, checked against the table's end once that's known
+    reach: (usize, usize),
+}
+
+impl<N: Accumulator> CopyCounter<N> {
+    fn new() -> Self {
+        CopyCounter {
+            cards: 0,
+            pending: VecDeque::new(),
+            total: Ok(N::zero()),
+            failed: None,
+            reach: (0, 0),
+        }
+    }
+
+    fn add(&mut self, score: u32) {
+        self.cards += 1;
+        let card = self.cards;
+        self.reach = furthest(self.reach, card, score);
+        if self.failed.is_some() {
+            return;
+        }
+
+        let copies = self.pending.pop_front().unwrap_or_else(N::one);
+        for won in 0..score as usize {
+            if won == self.pending.len() {
+                self.pending.push_back(N::one());
+            }
+            match self.pending[won].checked_add(&copies) {
+                Some(sum) => self.pending[won] = sum,
+                None => {
+                    self.failed = Some(N::overflow().on_line(card + won + 1));
+                    return;
+                }
+            }
+        }
+        stream::add(&mut self.total, card, || Ok(copies));
+    }
+
+    fn finish(self) -> SolveResult<N> {
+        let (card, last) = self.reach;
+        if last > self.cards {
+            return Err(SolveError::new(PAST_END).on_line(card));
+        }
+        if let Some(err) = self.failed {
+            return Err(err);
+        }
+        self.total
+    }
+}
+
+/// Both parts from one pass over `reader`, a card at a time.
+fn stream_totals<N: Accumulator>(reader: &mut dyn BufRead) -> StreamResult<N> {
+    let mut points = Ok(N::zero());
+    let mut copies = CopyCounter::new();
+    stream::for_each_line(reader, |line| {
+        let score = parse_card(line)?.score();
+        stream::add(&mut points, line.number, || worth(score, line.number));
+        copies.add(score);
+        Ok(())
+    })?;
+    Ok([points, copies.finish()])
+}
+
+/// Parses each line into a [`Card`].
+#[derive(Clone)]
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+    type Answer = u32;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Input> {
+        parse::lines(input).map(parse_card).collect()
+    }
+
+    fn part1(&self, cards: &Self::Input) -> SolveResult<u32> {
+        points(cards)
+    }
+
+    fn part2(&self, cards: &Self::Input) -> SolveResult<u32> {
+        total_cards(cards)
+    }
+
+    fn part_with(&self, cards: &Self::Input, part: u8, width: Width) -> SolveResult<String> {
+        with_width!(width, u32, |N| match part {
+            1 => points::<N>(cards),
+            _ => total_cards::<N>(cards),
+        })
+    }
+
+    fn stream(&self, reader: &mut dyn BufRead, width: Width) -> Option<StreamResult> {
+        Some(with_width!(width, u32, |N| stream_totals::<N>(reader)))
+    }
+
+    /// Each card's matching numbers, and for part 2 the copies of it in the end.
+    fn explain(&self, cards: &Self::Input, part: u8) -> Vec<Step> {
+        let copies = match part {
+            1 => None,
+            _ => match card_copies::<BigInt>(cards) {
+                Ok(copies) => Some(copies),
+                Err(err) => return vec![err.into()],
+            },
+        };
+        cards
+            .iter()
+            .enumerate()
+            .map(|(i, card)| {
+                let mut text = format!("card {}: matches {}", i + 1, card.score());
+                if let Some(copies) = &copies {
+                    text += &format!(", copies {}", copies[i]);
+                }
+                Step::new(text).on_line(i + 1)
+            })
+            .collect()
+    }
+}
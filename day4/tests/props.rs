@@ -0,0 +1,23 @@
+use aoc_common::Solution;
+use day4::Day4;
+use proptest::prelude::*;
+
+/// Cards this card and every copy it wins add up to.
+fn total_cards(scores: &[u32], card: usize) -> u32 {
+    let won = card + 1..card + 1 + scores[card] as usize;
+    1 + won.map(|next| total_cards(scores, next)).sum::<u32>()
+}
+
+proptest! {
+    #[test]
+    fn matches_following_every_copy(input in aoc_gen::day4::input(1..=20)) {
+        let cards = Day4.parse(&input).unwrap();
+        let scores: Vec<u32> = cards.iter().map(|card| card.score()).collect();
+
+        let points: u32 = scores.iter().filter(|&&score| score > 0).map(|score| 1 << (score - 1)).sum();
+        let total: u32 = (0..cards.len()).map(|card| total_cards(&scores, card)).sum();
+        prop_assert_eq!(Day4.part1(&cards), Ok(points));
+        prop_assert_eq!(Day4.part2(&cards), Ok(total));
+        prop_assert!(total >= cards.len() as u32);
+    }
+}
@@ -0,0 +1,221 @@
+//! Day 5: If You Give A Seed A Fertilizer. Follows seeds through the almanac's
+//! chain of maps to find the lowest location.
+
+use aoc_common::params;
+use aoc_common::parse::{self, Line};
+use aoc_common::query::{self, Query};
+use aoc_common::{ParseError, ParseResult, Solution, SolveError, SolveResult, Step};
+
+/// Maps `src..=src + range` onto `dest..=dest + range`.
+#[derive(Debug)]
+pub struct Mapping {
+    pub dest: u64,
+    pub src: u64,
+    pub range: u64,
+}
+
+/// One `x-to-y map:` section. Values not covered by any mapping map to themselves.
+pub type Map = Vec<Mapping>;
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    /// In order, from seed through to location
+    pub maps: Vec<Map>,
+}
+
+/// Parses a map header line followed by its mappings. Neither end of a mapping may
+/// run past `u64::MAX`.
+pub fn parse_map(block: &[Line]) -> ParseResult<Map> {
+    let (header, mappings) = block.split_first().unwrap();
+    if !header.text.trim_end().ends_with("map:") {
+        return Err(header.error(header.text, "expected a `... map:` header"));
+    }
+
+    mappings
+        .iter()
+        .map(|line| {
+            let [dest, src, range]: [u64; 3] = line.record(line.text)?;
+            if dest.checked_add(range).is_none() || src.checked_add(range).is_none() {
+                return Err(line.error(line.text, "mapping runs past the largest value"));
+            }
+            Ok(Mapping { dest, src, range })
+        })
+        .collect()
+}
+
+pub fn parse_almanac(input: &str) -> ParseResult<Almanac> {
+    let mut blocks = parse::blocks(input);
+
+    let seed_block = blocks
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "expected `seeds:` line"))?;
+    let seeds = seed_block[0].labelled("seeds")?;
+    if let Some(line) = seed_block.get(1) {
+        return Err(line.error(line.text, "expected a blank line after the seeds"));
+    }
+
+    let maps = blocks
+        .map(|block| parse_map(&block))
+        .collect::<ParseResult<_>>()?;
+
+    Ok(Almanac { seeds, maps })
+}
+
+/// Where `map` sends `src`.
+pub fn apply_map(map: &Map, src: u64) -> u64 {
+    for mapping in map.iter() {
+        if src >= mapping.src && src - mapping.src <= mapping.range {
+            return mapping.dest + (src - mapping.src);
+        }
+    }
+    src
+}
+
+/// Follows `seed` through each of `maps` in turn.
+pub fn apply_maps(maps: &[Map], seed: u64) -> u64 {
+    maps.iter().fold(seed, |src, map| apply_map(map, src))
+}
+
+/// Follows whole ranges of seeds through each of `maps` in turn, splitting them
+/// wherever a mapping starts or ends. Ranges include both ends.
+pub fn apply_maps_to_ranges(maps: &[Map], seeds: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+    maps.iter().fold(seeds, |ranges, map| {
+        let mut unmapped = ranges;
+        let mut mapped = Vec::new();
+        // The first mapping to cover a value wins, as in `apply_maps`
+        for mapping in map.iter() {
+            let (start, end) = (mapping.src, mapping.src + mapping.range);
+            let mut rest = Vec::new();
+            for (low, high) in unmapped {
+                if high < start || low > end {
+                    rest.push((low, high));
+                    continue;
+                }
+                if low < start {
+                    rest.push((low, start - 1));
+                }
+                if high > end {
+                    rest.push((end + 1, high));
+                }
+                let (low, high) = (low.max(start), high.min(end));
+                mapped.push((mapping.dest + (low - start), mapping.dest + (high - start)));
+            }
+            unmapped = rest;
+        }
+        mapped.extend(unmapped);
+        mapped
+    })
+}
+
+/// The seeds read as `start length` pairs, as inclusive ranges. Empty ranges are
+/// left out.
+pub fn seed_ranges(seeds: &[u64]) -> SolveResult<Vec<(u64, u64)>> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err(SolveError::new("seeds should come in start and length pairs").on_line(1));
+    }
+    seeds
+        .chunks(2)
+        .filter(|chunk| chunk[1] > 0)
+        .map(|chunk| {
+            chunk[0]
+                .checked_add(chunk[1] - 1)
+                .map(|end| (chunk[0], end))
+                .ok_or_else(|| SolveError::new("seed range runs past the largest value").on_line(1))
+        })
+        .collect()
+}
+
+fn format_ranges(ranges: &[(u64, u64)]) -> String {
+    let ranges: Vec<String> = ranges
+        .iter()
+        .map(|(low, high)| format!("{}..={}", low, high))
+        .collect();
+    ranges.join(" ")
+}
+
+/// Parses the input into an [`Almanac`].
+#[derive(Clone)]
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+    type Answer = u64;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Input> {
+        parse_almanac(input)
+    }
+
+    fn part1(&self, almanac: &Self::Input) -> SolveResult<u64> {
+        almanac
+            .seeds
+            .iter()
+            .map(|&seed| apply_maps(&almanac.maps, seed))
+            .min()
+            .ok_or_else(|| SolveError::new("no seeds").on_line(1))
+    }
+
+    fn part2(&self, almanac: &Self::Input) -> SolveResult<u64> {
+        apply_maps_to_ranges(&almanac.maps, seed_ranges(&almanac.seeds)?)
+            .iter()
+            .map(|&(low, _)| low)
+            .min()
+            .ok_or_else(|| SolveError::new("no seeds").on_line(1))
+    }
+
+    /// Part 1 shows each seed's value after every map, part 2 the ranges left
+    /// after every map.
+    fn explain(&self, almanac: &Self::Input, part: u8) -> Vec<Step> {
+        if part == 1 {
+            return almanac
+                .seeds
+                .iter()
+                .map(|&seed| {
+                    let chain: Vec<String> = almanac
+                        .maps
+                        .iter()
+                        .scan(seed, |value, map| {
+                            *value = apply_map(map, *value);
+                            Some(value.to_string())
+                        })
+                        .collect();
+                    Step::new(format!("seed {} -> {}", seed, chain.join(" -> ")))
+                })
+                .collect();
+        }
+
+        let mut ranges = match seed_ranges(&almanac.seeds) {
+            Ok(ranges) => ranges,
+            Err(err) => return vec![err.into()],
+        };
+        let mut steps = vec![Step::new(format!("seeds: {}", format_ranges(&ranges)))];
+        for (i, map) in almanac.maps.iter().enumerate() {
+            ranges = apply_maps_to_ranges(std::slice::from_ref(map), ranges);
+            ranges.sort();
+            steps.push(Step::new(format!(
+                "after map {}: {}",
+                i + 1,
+                format_ranges(&ranges)
+            )));
+        }
+        steps
+    }
+
+    fn queries(&self) -> Vec<Query> {
+        vec![Query::new(
+            "location",
+            "<seed>",
+            "where a seed ends up after every map",
+        )]
+    }
+
+    fn query(&self, almanac: &Self::Input, name: &str, args: &[&str]) -> Result<String, String> {
+        match name {
+            "location" => {
+                let [seed] = query::args(name, "<seed>", args)?;
+                let seed = params::parse("seed", seed)?;
+                Ok(apply_maps(&almanac.maps, seed).to_string())
+            }
+            _ => Err(query::unknown(name)),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::Solution;
+use day5::{apply_maps, Day5};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn lowest_locations_match_trying_every_seed(input in aoc_gen::day5::input(1..=4)) {
+        let almanac = Day5.parse(&input).unwrap();
+        let location = |seed| apply_maps(&almanac.maps, seed);
+
+        let lowest = almanac.seeds.iter().map(|&seed| location(seed)).min();
+        prop_assert_eq!(Day5.part1(&almanac).ok(), lowest);
+
+        let lowest_in_ranges = almanac
+            .seeds
+            .chunks(2)
+            .flat_map(|range| range[0]..range[0] + range[1])
+            .map(location)
+            .min();
+        prop_assert_eq!(Day5.part2(&almanac).ok(), lowest_in_ranges);
+    }
+}
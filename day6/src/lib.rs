@@ -0,0 +1,125 @@
+//! Day 6: Wait For It. Counts the ways to beat each boat race's record.
+
+use aoc_common::parse;
+use aoc_common::{
+    with_width, Accumulator, ParseError, ParseResult, Solution, SolveError, SolveResult, Width,
+};
+use std::iter::zip;
+
+/// Race durations and record distances, in the same order.
+pub struct Races {
+    pub time: Vec<u64>,
+    pub dist: Vec<u64>,
+}
+
+/// Reads a row of numbers as one number with the spaces removed, as part 2 does.
+/// `None` if there are no numbers or the result doesn't fit in `N`.
+pub fn join_digits<N: Accumulator>(values: &[u64]) -> Option<N> {
+    if values.is_empty() {
+        return None;
+    }
+    let ten = N::of(10)?;
+    values
+        .iter()
+        .flat_map(|value| value.to_string().into_bytes())
+        .try_fold(N::zero(), |joined, digit| {
+            joined.checked_mul(&ten)?.checked_add(&N::of(digit - b'0')?)
+        })
+}
+
+/// Whether holding the button for `hold` beats `dist` in a race of `time`,
+/// without working out the distance itself, which can be too big for `N`.
+fn beats<N: Accumulator>(hold: &N, time: &N, dist: &N) -> Option<bool> {
+    let rest = time.checked_sub(hold)?;
+    if rest == N::zero() {
+        return Some(false);
+    }
+    Some(*hold > dist.checked_div(&rest)?)
+}
+
+/// How many button holds beat a record of `dist` in a race of `time`. The shortest
+/// winning hold is found by a binary search, since the longest is the same
+/// distance from the end, so the count is exact for races of any length. `None`
+/// only if the search overflows `N`.
+pub fn ways_to_win<N: Accumulator>(time: &N, dist: &N) -> Option<N> {
+    let two = N::of(2)?;
+    let half = time.checked_div(&two)?;
+    if !beats(&half, time, dist)? {
+        return Some(N::zero());
+    }
+    let (mut lower, mut upper) = (N::zero(), half);
+    while lower < upper {
+        let mid = lower.checked_add(&upper)?.checked_div(&two)?;
+        if beats(&mid, time, dist)? {
+            upper = mid;
+        } else {
+            lower = mid.checked_add(&N::one())?;
+        }
+    }
+    time.checked_sub(&lower)?
+        .checked_sub(&lower)?
+        .checked_add(&N::one())
+}
+
+/// The number of ways to win each race, multiplied together.
+pub fn margin_product<N: Accumulator>(races: &Races) -> SolveResult<N> {
+    zip(&races.time, &races.dist)
+        .try_fold(N::one(), |product, (&time, &dist)| {
+            product.checked_mul(&ways_to_win(&N::of(time)?, &N::of(dist)?)?)
+        })
+        // Races are columns rather than lines, so put it down to the `Time:` line
+        .ok_or_else(|| N::overflow().on_line(1))
+}
+
+/// The number of ways to win the one long race part 2 reads the rows as.
+pub fn single_race<N: Accumulator>(races: &Races) -> SolveResult<N> {
+    let time = join_digits::<N>(&races.time)
+        .ok_or_else(|| SolveError::new("times don't join into one number").on_line(1))?;
+    let dist = join_digits::<N>(&races.dist)
+        .ok_or_else(|| SolveError::new("distances don't join into one number").on_line(2))?;
+    ways_to_win(&time, &dist).ok_or_else(|| N::overflow().on_line(1))
+}
+
+/// Parses the two lines into [`Races`].
+#[derive(Clone)]
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Races;
+    type Answer = u64;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Input> {
+        let mut lines = parse::lines(input);
+        let time_line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "expected a `Time:` line"))?;
+        let dist_line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(2, 1, "", "expected a `Distance:` line"))?;
+
+        let time: Vec<u64> = time_line.labelled("Time")?;
+        let dist: Vec<u64> = dist_line.labelled("Distance")?;
+        if time.len() != dist.len() {
+            let message = format!("expected {} distances, found {}", time.len(), dist.len());
+            return Err(dist_line.error(dist_line.text, message));
+        }
+        Ok(Races { time, dist })
+    }
+
+    fn part1(&self, races: &Self::Input) -> SolveResult<u64> {
+        margin_product(races)
+    }
+
+    fn part2(&self, races: &Self::Input) -> SolveResult<u64> {
+        single_race(races)
+    }
+
+    /// Part 2's rows can join into numbers too long for a `u64`, which a wider
+    /// `width` reads as well.
+    fn part_with(&self, races: &Self::Input, part: u8, width: Width) -> SolveResult<String> {
+        match part {
+            1 => with_width!(width, u64, |N| margin_product::<N>(races)),
+            _ => with_width!(width, u64, |N| single_race::<N>(races)),
+        }
+    }
+}
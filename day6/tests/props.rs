@@ -0,0 +1,64 @@
+use aoc_common::Solution;
+use day6::{ways_to_win, Day6};
+use proptest::prelude::*;
+
+fn count_wins(time: u64, dist: u64) -> u64 {
+    (0..=time)
+        .filter(|hold| hold * (time - hold) > dist)
+        .count() as u64
+}
+
+/// Whether `ways` is the right count for a race too long to try every hold in:
+/// the shortest winning hold it implies must win, and the hold before it lose.
+fn is_exact(time: u64, dist: u64, ways: u64) -> bool {
+    let distance = |hold: u64| u128::from(hold) * u128::from(time - hold);
+    let shortest = (time + 1 - ways) / 2;
+    (time + 1 - ways).is_multiple_of(2)
+        && distance(shortest) > u128::from(dist)
+        && (shortest == 0 || distance(shortest - 1) <= u128::from(dist))
+}
+
+proptest! {
+    #[test]
+    fn ways_match_trying_every_hold((time, dist) in aoc_gen::day6::race()) {
+        prop_assert_eq!(ways_to_win(&time, &dist), Some(count_wins(time, dist)));
+    }
+
+    #[test]
+    fn long_races_are_counted_exactly(
+        (time, dist) in aoc_gen::day6::race_in(10_000_000..4_000_000_000)
+    ) {
+        let ways = ways_to_win(&time, &dist).unwrap();
+        prop_assert!(is_exact(time, dist, ways), "{} ways", ways);
+    }
+
+    #[test]
+    fn some_records_cant_be_beaten(time in 0..100u64, extra in 0..100u64) {
+        let dist = (time / 2) * (time - time / 2) + extra;
+        prop_assert_eq!(ways_to_win(&time, &dist), Some(0));
+    }
+
+    #[test]
+    fn part1_multiplies_ways_to_win(input in aoc_gen::day6::input(1..=4)) {
+        let races = Day6.parse(&input).unwrap();
+        let product: u64 = races
+            .time
+            .iter()
+            .zip(&races.dist)
+            .map(|(&time, &dist)| count_wins(time, dist))
+            .product();
+        prop_assert_eq!(Day6.part1(&races), Ok(product));
+    }
+}
+
+#[test]
+fn records_past_what_a_float_holds_exactly() {
+    assert_eq!(
+        ways_to_win(&516090196u64, &30551634508866003),
+        Some(379661101)
+    );
+    let races = Day6
+        .parse("Time: 516090196\nDistance: 30551634508866003\n")
+        .unwrap();
+    assert_eq!(Day6.part1(&races), Ok(379661101));
+}
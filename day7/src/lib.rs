@@ -0,0 +1,403 @@
+//! Day 7: Camel Cards. Ranks poker-like hands and totals the winnings, with and
+//! without jokers.
+
+use aoc_common::parse::{self, Line};
+use aoc_common::query::{self, Query};
+use aoc_common::stream;
+use aoc_common::{
+    with_width, Accumulator, ParseResult, Solution, SolveError, SolveResult, StreamResult, Width,
+};
+use std::cmp;
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::iter::zip;
+
+/// Hand types from strongest to weakest, so stronger hands compare as less.
+#[derive(Debug, PartialOrd, PartialEq)]
+pub enum HandType {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    pub hand_type: HandType,
+    pub cards: String,
+}
+
+impl Hand {
+    pub fn new(hand_type: HandType, cards: String) -> Self {
+        Hand { hand_type, cards }
+    }
+
+    /// Orders hands strongest first, breaking ties card by card using `index`.
+    pub fn compare(&self, other: &Self, index: fn(char) -> Option<usize>) -> cmp::Ordering {
+        if self.hand_type == other.hand_type {
+            for (card_a, card_b) in zip(self.cards.chars(), other.cards.chars()) {
+                if card_a != card_b {
+                    return index(card_a).cmp(&index(card_b));
+                }
+            }
+            cmp::Ordering::Equal
+        } else {
+            self.hand_type.partial_cmp(&other.hand_type).unwrap()
+        }
+    }
+}
+
+/// Every card label, strongest first.
+pub const CARDS: &str = "AKQJT98765432";
+
+/// Strength rank of `card`, where 0 is the strongest. `None` if it isn't a card.
+pub fn card_index(card: char) -> Option<usize> {
+    CARDS.find(card)
+}
+
+/// Like `card_index`, but with `J` as the weakest card.
+pub fn card_index_with_jokers(card: char) -> Option<usize> {
+    match card {
+        'J' => Some(12),
+        'A' | 'K' | 'Q' => card_index(card),
+        _ => Some(card_index(card)? - 1),
+    }
+}
+
+/// How many of each card the hand holds, indexed by `index`. `None` if any of
+/// them isn't a card.
+pub fn get_card_counts(hand_str: &str, index: fn(char) -> Option<usize>) -> Option<Vec<u32>> {
+    let mut count = vec![0; 13];
+    for card in hand_str.chars() {
+        count[index(card)?] += 1;
+    }
+    Some(count)
+}
+
+/// Classifies the hand, or `None` if it holds something that isn't a card.
+pub fn get_hand(hand_str: &str) -> Option<Hand> {
+    let card_counts = get_card_counts(hand_str, card_index)?;
+
+    let hand_string = hand_str.to_string();
+    if card_counts.contains(&5) {
+        return Some(Hand::new(HandType::FiveOfAKind, hand_string));
+    } else if card_counts.contains(&4) {
+        return Some(Hand::new(HandType::FourOfAKind, hand_string));
+    } else if card_counts.contains(&3) {
+        if card_counts.contains(&2) {
+            return Some(Hand::new(HandType::FullHouse, hand_string));
+        }
+        return Some(Hand::new(HandType::ThreeOfAKind, hand_string));
+    } else if card_counts.contains(&2) {
+        if card_counts.iter().filter(|&c| c == &2).count() == 2 {
+            return Some(Hand::new(HandType::TwoPair, hand_string));
+        }
+        return Some(Hand::new(HandType::OnePair, hand_string));
+    }
+    Some(Hand::new(HandType::HighCard, hand_string))
+}
+
+/// Classifies the hand treating each `J` as whichever card makes it strongest.
+pub fn get_hand_with_jokers(hand_str: &str) -> Option<Hand> {
+    if !hand_str.contains('J') {
+        return get_hand(hand_str);
+    }
+
+    let mut card_counts = get_card_counts(hand_str, card_index_with_jokers)?;
+    let jokers = card_counts.pop().unwrap();
+    let max_count = *card_counts.iter().max().unwrap();
+    let hand_string = hand_str.to_string();
+
+    if max_count + jokers == 5 {
+        return Some(Hand::new(HandType::FiveOfAKind, hand_string));
+    } else if max_count + jokers == 4 {
+        return Some(Hand::new(HandType::FourOfAKind, hand_string));
+    } else if max_count == 3 || max_count + jokers >= 3 {
+        let jokers_remaining = jokers - (3 - max_count);
+        let min_count = *card_counts.iter().filter(|&x| x > &0).min().unwrap();
+        if min_count == 2 || min_count + jokers_remaining == 2 {
+            return Some(Hand::new(HandType::FullHouse, hand_string));
+        }
+        return Some(Hand::new(HandType::ThreeOfAKind, hand_string));
+    } else if max_count == 2 && jokers == 1 {
+        return Some(Hand::new(HandType::TwoPair, hand_string));
+    }
+    Some(Hand::new(HandType::OnePair, hand_string))
+}
+
+/// A hand's cards and its bid.
+pub type Round = (String, u32);
+
+/// For a hand holding something that isn't a card. Parsing rules that out, but a
+/// [`Round`] can be made without it.
+fn not_a_hand(cards: &str, line: usize) -> SolveError {
+    SolveError::new(format!("`{}` isn't a hand of cards", cards)).on_line(line)
+}
+
+/// `get_hand` for the hand dealt on `line`.
+fn hand_on(get_hand: fn(&str) -> Option<Hand>, cards: &str, line: usize) -> SolveResult<Hand> {
+    get_hand(cards).ok_or_else(|| not_a_hand(cards, line))
+}
+
+/// Every round's hand with its line and bid, weakest first, so each one's rank
+/// is its position plus one. Of two equal hands, the later one ranks lower.
+pub fn ranked(
+    rounds: &[Round],
+    get_hand: fn(&str) -> Option<Hand>,
+    card_index: fn(char) -> Option<usize>,
+) -> SolveResult<Vec<(usize, Hand, u32)>> {
+    let mut hands: Vec<(usize, Hand, u32)> = rounds
+        .iter()
+        .zip(1..)
+        .map(|((cards, bid), line)| Ok((line, hand_on(get_hand, cards, line)?, *bid)))
+        .collect::<SolveResult<_>>()?;
+
+    hands.sort_by(|hand_a, hand_b| hand_a.1.compare(&hand_b.1, card_index));
+    hands.reverse();
+    Ok(hands)
+}
+
+/// Total winnings: each bid multiplied by its hand's rank, weakest first. Overflow
+/// is reported on the line of the hand that tipped the total over, or for a hand
+/// dealt more than once, on its last copy.
+pub fn get_score<N: Accumulator>(
+    rounds: &[Round],
+    get_hand: fn(&str) -> Option<Hand>,
+    card_index: fn(char) -> Option<usize>,
+) -> SolveResult<N> {
+    let hands = ranked(rounds, get_hand, card_index)?;
+    let mut total = N::zero();
+    let mut blamed = 0;
+    for (i, (line, hand, bid)) in hands.iter().enumerate() {
+        // The last copy of a hand ranks lowest, so it comes first
+        if i == 0 || hands[i - 1].1.cards != hand.cards {
+            blamed = *line;
+        }
+        total = N::of(i as u64 + 1)
+            .zip(N::of(*bid))
+            .and_then(|(rank, bid)| rank.checked_mul(&bid))
+            .and_then(|winnings| total.checked_add(&winnings))
+            .ok_or_else(|| N::overflow().on_line(blamed))?;
+    }
+    Ok(total)
+}
+
+/// Bids on one distinct hand, for ranking while streaming.
+struct Tied<N> {
+    /// Every bid on the hand
+    bids: N,
+    /// Each bid times how many of the same hand were dealt after it. `get_score`
+    /// ranks later copies of a hand lower, so this is what they add on top of
+    /// the lowest rank the hand has.
+    raised: N,
+    count: u64,
+    /// Where the hand was last dealt, for overflow errors
+    line: usize,
+    /// Set once `bids` or `raised` overflows, which the total then does too
+    overflowed: bool,
+}
+
+impl<N: Accumulator> Tied<N> {
+    /// What every copy of the hand wins, when the last one dealt has `lowest_rank`.
+    fn winnings(&self, lowest_rank: u64) -> Option<N> {
+        if self.overflowed {
+            return None;
+        }
+        N::of(lowest_rank)?
+            .checked_mul(&self.bids)?
+            .checked_add(&self.raised)
+    }
+}
+
+/// Winnings worked out as hands are dealt, without keeping every hand. Hands
+/// are grouped by how they sort, and there are at most 13^5 of those, so memory
+/// doesn't grow with the input.
+struct Ranker<N> {
+    get_hand: fn(&str) -> Option<Hand>,
+    card_index: fn(char) -> Option<usize>,
+    /// Keyed by hand type then card strengths, so the strongest hand comes first
+    hands: BTreeMap<(u8, [u8; 5]), Tied<N>>,
+    /// The first hand that isn't one, as `get_score` would find it
+    failed: Option<SolveError>,
+}
+
+impl<N: Accumulator> Ranker<N> {
+    fn new(get_hand: fn(&str) -> Option<Hand>, card_index: fn(char) -> Option<usize>) -> Self {
+        Ranker {
+            get_hand,
+            card_index,
+            hands: BTreeMap::new(),
+            failed: None,
+        }
+    }
+
+    /// Where `cards` sorts among the hands.
+    fn key(&self, cards: &str, line: usize) -> SolveResult<(u8, [u8; 5])> {
+        let hand = hand_on(self.get_hand, cards, line)?;
+        let mut strengths = [0; 5];
+        for (strength, card) in zip(&mut strengths, cards.chars()) {
+            *strength = (self.card_index)(card).ok_or_else(|| not_a_hand(cards, line))? as u8;
+        }
+        Ok((hand.hand_type as u8, strengths))
+    }
+
+    fn add(&mut self, cards: &str, bid: u32, line: usize) {
+        if self.failed.is_some() {
+            return;
+        }
+        let key = match self.key(cards, line) {
+            Ok(key) => key,
+            Err(err) => {
+                self.failed = Some(err);
+                return;
+            }
+        };
+
+        let tied = self.hands.entry(key).or_insert_with(|| Tied {
+            bids: N::zero(),
+            raised: N::zero(),
+            count: 0,
+            line,
+            overflowed: false,
+        });
+        tied.count += 1;
+        tied.line = line;
+        if tied.overflowed {
+            return;
+        }
+        let raised = tied.raised.checked_add(&tied.bids);
+        let bids = N::of(bid).and_then(|bid| tied.bids.checked_add(&bid));
+        match raised.zip(bids) {
+            Some((raised, bids)) => {
+                tied.raised = raised;
+                tied.bids = bids;
+            }
+            None => tied.overflowed = true,
+        }
+    }
+
+    /// Total winnings, failing on the same line as `get_score`. Winnings only
+    /// ever add up, so the total first overflows on the same hand either way.
+    fn finish(self) -> SolveResult<N> {
+        if let Some(err) = self.failed {
+            return Err(err);
+        }
+        let mut lowest_rank = 1u64;
+        self.hands
+            .values()
+            .rev()
+            .try_fold(N::zero(), |total, tied| {
+                let sum = tied
+                    .winnings(lowest_rank)
+                    .and_then(|winnings| total.checked_add(&winnings));
+                lowest_rank += tied.count;
+                sum.ok_or_else(|| N::overflow().on_line(tied.line))
+            })
+    }
+}
+
+/// Both parts from one pass over `reader`, a hand at a time.
+fn stream_scores<N: Accumulator>(reader: &mut dyn BufRead) -> StreamResult<N> {
+    let mut plain = Ranker::new(get_hand, card_index);
+    let mut jokers = Ranker::new(get_hand_with_jokers, card_index_with_jokers);
+    stream::for_each_line(reader, |line| {
+        let (cards, bid) = parse_round(line)?;
+        plain.add(&cards, bid, line.number);
+        jokers.add(&cards, bid, line.number);
+        Ok(())
+    })?;
+    Ok([plain.finish(), jokers.finish()])
+}
+
+/// Parses a `32T3K 765` line.
+pub fn parse_round(line: Line) -> ParseResult<Round> {
+    let [cards, bid] = line.fields::<2>(line.text)?;
+    if let Some((i, card)) = cards.char_indices().find(|&(_, c)| !CARDS.contains(c)) {
+        return Err(line.error(&cards[i..i + card.len_utf8()], "unexpected card"));
+    }
+    if cards.len() != 5 {
+        return Err(line.error(cards, "expected five cards"));
+    }
+
+    Ok((cards.to_string(), line.parse(bid)?))
+}
+
+/// `cards`' rank and hand type among `rounds`, as `ranked` orders them.
+fn rank_of(
+    rounds: &[Round],
+    cards: &str,
+    get_hand: fn(&str) -> Option<Hand>,
+    card_index: fn(char) -> Option<usize>,
+) -> Result<(usize, HandType), String> {
+    ranked(rounds, get_hand, card_index)
+        .map_err(|err| err.to_string())?
+        .into_iter()
+        .enumerate()
+        .find(|(_, (_, hand, _))| hand.cards == cards)
+        .map(|(i, (_, hand, _))| (i + 1, hand.hand_type))
+        .ok_or_else(|| format!("no hand `{}` in the input", cards))
+}
+
+/// Parses each line into a [`Round`]; hands are classified per part since
+/// jokers change their type.
+#[derive(Clone)]
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Round>;
+    type Answer = u32;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Input> {
+        parse::lines(input).map(parse_round).collect()
+    }
+
+    fn part1(&self, rounds: &Self::Input) -> SolveResult<u32> {
+        get_score(rounds, get_hand, card_index)
+    }
+
+    fn part2(&self, rounds: &Self::Input) -> SolveResult<u32> {
+        get_score(rounds, get_hand_with_jokers, card_index_with_jokers)
+    }
+
+    fn part_with(&self, rounds: &Self::Input, part: u8, width: Width) -> SolveResult<String> {
+        with_width!(width, u32, |N| match part {
+            1 => get_score::<N>(rounds, get_hand, card_index),
+            _ => get_score::<N>(rounds, get_hand_with_jokers, card_index_with_jokers),
+        })
+    }
+
+    fn stream(&self, reader: &mut dyn BufRead, width: Width) -> Option<StreamResult> {
+        Some(with_width!(width, u32, |N| stream_scores::<N>(reader)))
+    }
+
+    fn queries(&self) -> Vec<Query> {
+        vec![Query::new(
+            "rank",
+            "<hand>",
+            "a hand's rank and type, without and with jokers",
+        )]
+    }
+
+    fn query(&self, rounds: &Self::Input, name: &str, args: &[&str]) -> Result<String, String> {
+        match name {
+            "rank" => {
+                let [cards] = query::args(name, "<hand>", args)?;
+                let (plain, plain_type) = rank_of(rounds, cards, get_hand, card_index)?;
+                let (jokers, jokers_type) =
+                    rank_of(rounds, cards, get_hand_with_jokers, card_index_with_jokers)?;
+                Ok(format!(
+                    "rank {} of {} as {:?}, and {} with jokers as {:?}",
+                    plain,
+                    rounds.len(),
+                    plain_type,
+                    jokers,
+                    jokers_type
+                ))
+            }
+            _ => Err(query::unknown(name)),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use aoc_common::Solution;
+use day7::{card_index, get_hand, get_hand_with_jokers, Day7, HandType};
+use proptest::prelude::*;
+
+/// Hand type from the sorted card counts, largest first.
+fn hand_type(cards: &str) -> HandType {
+    let mut counts: Vec<usize> = cards
+        .chars()
+        .map(|card| cards.matches(card).count())
+        .collect();
+    counts.sort_by(|a, b| b.cmp(a));
+    match counts[..] {
+        [5, ..] => HandType::FiveOfAKind,
+        [4, ..] => HandType::FourOfAKind,
+        [3, 3, 3, 2, ..] => HandType::FullHouse,
+        [3, ..] => HandType::ThreeOfAKind,
+        [2, 2, 2, 2, ..] => HandType::TwoPair,
+        [2, ..] => HandType::OnePair,
+        _ => HandType::HighCard,
+    }
+}
+
+/// The best hand type from replacing every joker with the same other card.
+fn best_with_jokers(cards: &str) -> HandType {
+    "AKQT98765432"
+        .chars()
+        .map(|card| hand_type(&cards.replace('J', &card.to_string())))
+        .fold(
+            hand_type(cards),
+            |best, hand| if hand < best { hand } else { best },
+        )
+}
+
+proptest! {
+    #[test]
+    fn hand_types_match_card_counts(cards in aoc_gen::day7::hand()) {
+        prop_assert_eq!(get_hand(&cards).unwrap().hand_type, hand_type(&cards));
+        prop_assert_eq!(get_hand_with_jokers(&cards).unwrap().hand_type, best_with_jokers(&cards));
+    }
+
+    #[test]
+    fn jokers_never_weaken_a_hand(cards in aoc_gen::day7::hand()) {
+        // Stronger hand types compare as less
+        prop_assert!(get_hand_with_jokers(&cards).unwrap().hand_type <= get_hand(&cards).unwrap().hand_type);
+        if !cards.contains('J') {
+            prop_assert_eq!(get_hand_with_jokers(&cards).unwrap().hand_type, get_hand(&cards).unwrap().hand_type);
+        }
+    }
+
+    #[test]
+    fn every_rank_is_used_once(input in aoc_gen::day7::input(1..=20)) {
+        let mut rounds = Day7.parse(&input).unwrap();
+        rounds.iter_mut().for_each(|round| round.1 = 1);
+        let ranks = (rounds.len() * (rounds.len() + 1) / 2) as u32;
+        prop_assert_eq!(Day7.part1(&rounds), Ok(ranks));
+        prop_assert_eq!(Day7.part2(&rounds), Ok(ranks));
+    }
+}
+
+#[test]
+fn anything_but_cards_is_an_error_rather_than_a_panic() {
+    assert_eq!(card_index('X'), None);
+    assert!(get_hand("AAXAA").is_none());
+    assert!(get_hand_with_jokers("JJ1JJ").is_none());
+
+    let rounds = vec![("32T3K".to_string(), 765), ("KX677".to_string(), 28)];
+    let err = "line 2: `KX677` isn't a hand of cards";
+    assert_eq!(Day7.part1(&rounds).unwrap_err().to_string(), err);
+    assert_eq!(Day7.part2(&rounds).unwrap_err().to_string(), err);
+    assert_eq!(
+        Day7.query(&rounds, "rank", &["32T3K"]),
+        Err(err.to_string())
+    );
+}
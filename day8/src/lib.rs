@@ -0,0 +1,321 @@
+//! Day 8: Haunted Wasteland. Walks the left/right network, both for one traveller
+//! and for every ghost at once.
+
+use aoc_common::num::lcm;
+use aoc_common::params::{self, Param};
+use aoc_common::parse::{self, Line};
+use aoc_common::query::{self, Query};
+use aoc_common::{
+    with_width, Accumulator, ParseError, ParseResult, Solution, SolveError, SolveResult, Width,
+};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Left and right destinations from each node.
+pub type Network = HashMap<String, [String; 2]>;
+
+pub struct Maps {
+    /// 0 to go left, 1 to go right
+    pub instructions: Vec<usize>,
+    pub network: Network,
+}
+
+/// Follows the instructions from `start_node` until `end_cond` holds, returning the
+/// number of steps taken and the node it stopped on. Fails on a node missing from
+/// the network, or a walk that goes round in circles without ever stopping.
+pub fn get_steps(
+    start_node: &str,
+    network: &Network,
+    instructions: &[usize],
+    end_cond: impl Fn(&str) -> bool,
+) -> SolveResult<(u64, String)> {
+    // Past this many steps some node has come up twice at the same point in the
+    // instructions, and everything from there on repeats
+    let limit = (network.len() as u64).saturating_mul(instructions.len() as u64);
+
+    let mut cur_node = start_node;
+    for (steps, &direction) in (1..=limit).zip(instructions.iter().cycle()) {
+        let targets = network
+            .get(cur_node)
+            .ok_or_else(|| SolveError::new(format!("no node `{}` in the network", cur_node)))?;
+        cur_node = &targets[direction];
+        if end_cond(cur_node) {
+            return Ok((steps, cur_node.to_string()));
+        }
+    }
+    let message = format!("the walk from `{}` never ends", start_node);
+    Err(SolveError::new(message))
+}
+
+/// Takes `count` steps from `node`, the first of them being step `from` of the
+/// walk so the instructions pick up where they left off.
+fn advance<'a>(
+    mut node: &'a str,
+    network: &'a Network,
+    instructions: &[usize],
+    from: u64,
+    count: u64,
+) -> SolveResult<&'a str> {
+    let len = instructions.len() as u64;
+    for step in from..from + count {
+        let targets = network
+            .get(node)
+            .ok_or_else(|| SolveError::new(format!("no node `{}` in the network", node)))?;
+        node = &targets[instructions[(step % len) as usize]];
+    }
+    Ok(node)
+}
+
+/// The node `steps` steps from `start_node`, following the instructions. Any
+/// number of steps is fine, since a walk longer than `get_steps`'s limit is
+/// going round a loop, and only its remainder over that loop is walked.
+pub fn walk(
+    start_node: &str,
+    network: &Network,
+    instructions: &[usize],
+    steps: u64,
+) -> SolveResult<String> {
+    if instructions.is_empty() {
+        return Err(SolveError::new("no instructions to follow"));
+    }
+    let limit = (network.len() as u64).saturating_mul(instructions.len() as u64);
+    if steps <= limit {
+        return Ok(advance(start_node, network, instructions, 0, steps)?.to_string());
+    }
+
+    // Round the loop until back on the same node at the same point in the
+    // instructions
+    let looping = advance(start_node, network, instructions, 0, limit)?;
+    let mut node = looping;
+    let mut period = 0;
+    loop {
+        node = advance(node, network, instructions, limit + period, 1)?;
+        period += 1;
+        if node == looping && period % instructions.len() as u64 == 0 {
+            break;
+        }
+    }
+    let rest = (steps - limit) % period;
+    Ok(advance(looping, network, instructions, limit, rest)?.to_string())
+}
+
+fn parse_node(line: &Line) -> ParseResult<(String, [String; 2])> {
+    let (key, targets) = line.split_once(line.text, "=")?;
+    let targets = targets.trim();
+    let pair = targets
+        .strip_prefix('(')
+        .and_then(|targets| targets.strip_suffix(')'))
+        .ok_or_else(|| line.error(targets, "expected `(left, right)`"))?;
+    let (left, right) = line.split_once(pair, ",")?;
+
+    let [key] = line.fields::<1>(key)?;
+    let [left] = line.fields::<1>(left)?;
+    let [right] = line.fields::<1>(right)?;
+    Ok((key.to_string(), [left.to_string(), right.to_string()]))
+}
+
+pub fn parse_maps(input: &str) -> ParseResult<Maps> {
+    let mut blocks = parse::blocks(input);
+
+    let instruction_block = blocks
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "expected a line of `L`/`R` instructions"))?;
+    let line = instruction_block[0];
+    let instructions: Vec<usize> = line
+        .text
+        .char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(0),
+            'R' => Ok(1),
+            _ => Err(line.error(&line.text[i..i + c.len_utf8()], "expected `L` or `R`")),
+        })
+        .collect::<ParseResult<_>>()?;
+    if let Some(line) = instruction_block.get(1) {
+        return Err(line.error(line.text, "expected a blank line after the instructions"));
+    }
+
+    let network: Network = blocks
+        .flatten()
+        .map(|line| parse_node(&line))
+        .collect::<ParseResult<_>>()?;
+
+    Ok(Maps {
+        instructions,
+        network,
+    })
+}
+
+/// Steps until every ghost is on a node ending in `end` at once, when one starts
+/// from each node ending in `start`. This is the LCM of each ghost's first
+/// arrival, which only holds if every ghost keeps coming back to the same end
+/// node just as often, so that's checked and anything else is an error.
+pub fn ghost_steps<N: Accumulator>(maps: &Maps, start: &str, end: &str) -> SolveResult<N> {
+    let mut starts: Vec<&String> = maps
+        .network
+        .keys()
+        .filter(|node| node.ends_with(start))
+        .collect();
+    if starts.is_empty() {
+        let message = format!("no nodes ending in `{}` to start from", start);
+        return Err(SolveError::new(message));
+    }
+    // Sorted so any error comes from the same ghost every time
+    starts.sort();
+
+    let ends = |node: &str| node.ends_with(end);
+    starts.iter().try_fold(N::one(), |acc, start| {
+        let (steps, node) = get_steps(start, &maps.network, &maps.instructions, ends)?;
+        // A lone ghost is done the first time it gets there. More only line up at
+        // the LCM of their first arrivals if each keeps coming back on the beat.
+        if starts.len() > 1 {
+            check_loop(start, &node, steps, maps, end)?;
+        }
+        let steps = N::of(steps).ok_or_else(N::overflow)?;
+        lcm(&acc, &steps).ok_or_else(N::overflow)
+    })
+}
+
+/// Checks that the ghost from `start`, which first reaches `node` after `steps`
+/// steps, reaches no other end node and is back on `node` every `steps` steps
+/// after that. Once it's also back at the same point in the instructions, the
+/// walk repeats exactly, so that's as far as it needs following.
+fn check_loop(start: &str, node: &str, steps: u64, maps: &Maps, end: &str) -> SolveResult<()> {
+    let len = maps.instructions.len();
+    let first = (steps % len as u64) as usize;
+    let mut at = first;
+    loop {
+        let rotated: Vec<usize> = maps.instructions[at..]
+            .iter()
+            .chain(&maps.instructions[..at])
+            .copied()
+            .collect();
+        let (again, next) = get_steps(node, &maps.network, &rotated, |node: &str| {
+            node.ends_with(end)
+        })?;
+        if again != steps || next != node {
+            let message = format!(
+                "the ghost from `{}` doesn't come back to `{}` every {} steps, so the ghosts can't be lined up",
+                start, node, steps
+            );
+            return Err(SolveError::new(message));
+        }
+        at = (at + (steps % len as u64) as usize) % len;
+        if at == first {
+            return Ok(());
+        }
+    }
+}
+
+/// Parses the input into [`Maps`].
+#[derive(Clone)]
+pub struct Day8 {
+    /// Where part 1 starts and stops
+    pub start: Cow<'static, str>,
+    pub end: Cow<'static, str>,
+    /// Endings of the nodes part 2's ghosts start and stop on
+    pub ghost_start: Cow<'static, str>,
+    pub ghost_end: Cow<'static, str>,
+}
+
+impl Day8 {
+    pub const DEFAULT: Day8 = Day8 {
+        start: Cow::Borrowed("AAA"),
+        end: Cow::Borrowed("ZZZ"),
+        ghost_start: Cow::Borrowed("A"),
+        ghost_end: Cow::Borrowed("Z"),
+    };
+}
+
+impl Default for Day8 {
+    fn default() -> Self {
+        Day8::DEFAULT
+    }
+}
+
+impl Solution for Day8 {
+    type Input = Maps;
+    type Answer = u64;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Input> {
+        parse_maps(input)
+    }
+
+    fn part1(&self, maps: &Self::Input) -> SolveResult<u64> {
+        let (steps, _) = get_steps(&self.start, &maps.network, &maps.instructions, |node| {
+            node == self.end
+        })?;
+        Ok(steps)
+    }
+
+    fn part2(&self, maps: &Self::Input) -> SolveResult<u64> {
+        ghost_steps(maps, &self.ghost_start, &self.ghost_end)
+    }
+
+    fn part_with(&self, maps: &Self::Input, part: u8, width: Width) -> SolveResult<String> {
+        match part {
+            1 => with_width!(width, u64, |N| self
+                .part1(maps)
+                .and_then(|steps| N::of(steps).ok_or_else(N::overflow))),
+            _ => with_width!(width, u64, |N| ghost_steps::<N>(
+                maps,
+                &self.ghost_start,
+                &self.ghost_end
+            )),
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("start", &self.start, "node part 1 starts from"),
+            Param::new("end", &self.end, "node part 1 stops at"),
+            Param::new(
+                "ghost_start",
+                &self.ghost_start,
+                "ending of the nodes part 2's ghosts start from",
+            ),
+            Param::new(
+                "ghost_end",
+                &self.ghost_end,
+                "ending of the nodes part 2's ghosts stop at",
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let field = match name {
+            "start" => &mut self.start,
+            "end" => &mut self.end,
+            "ghost_start" => &mut self.ghost_start,
+            "ghost_end" => &mut self.ghost_end,
+            _ => return Err(params::unknown(name)),
+        };
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(format!("`{}` can't be empty", name));
+        }
+        *field = Cow::Owned(value.to_string());
+        Ok(())
+    }
+
+    fn queries(&self) -> Vec<Query> {
+        vec![Query::new(
+            "walk",
+            "<node> <steps>",
+            "the node reached after some steps from a node",
+        )]
+    }
+
+    fn query(&self, maps: &Self::Input, name: &str, args: &[&str]) -> Result<String, String> {
+        match name {
+            "walk" => {
+                let [node, steps] = query::args(name, "<node> <steps>", args)?;
+                let steps = params::parse("steps", steps)?;
+                if !maps.network.contains_key(node) {
+                    return Err(format!("no node `{}` in the network", node));
+                }
+                walk(node, &maps.network, &maps.instructions, steps).map_err(|err| err.to_string())
+            }
+            _ => Err(query::unknown(name)),
+        }
+    }
+}
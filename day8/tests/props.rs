@@ -0,0 +1,61 @@
+use aoc_common::Solution;
+use day8::{get_steps, walk, Day8};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn walk_ends_on_zzz(input in aoc_gen::day8::input(0..=30)) {
+        let maps = Day8::DEFAULT.parse(&input).unwrap();
+        let (steps, node) = get_steps("AAA", &maps.network, &maps.instructions, |node| node == "ZZZ").unwrap();
+        prop_assert_eq!(node, "ZZZ");
+        prop_assert_eq!(Day8::DEFAULT.part1(&maps), Ok(steps));
+    }
+
+    #[test]
+    fn repeating_the_instructions_changes_nothing(input in aoc_gen::day8::input(0..=30)) {
+        let mut maps = Day8::DEFAULT.parse(&input).unwrap();
+        let steps = Day8::DEFAULT.part1(&maps);
+        maps.instructions = maps.instructions.repeat(2);
+        prop_assert_eq!(Day8::DEFAULT.part1(&maps), steps);
+    }
+
+    #[test]
+    fn one_ghost_walks_like_part1(input in aoc_gen::day8::input(0..=30)) {
+        let maps = Day8::DEFAULT.parse(&input).unwrap();
+        prop_assert_eq!(Day8::DEFAULT.part2(&maps), Day8::DEFAULT.part1(&maps));
+    }
+
+    #[test]
+    fn walking_any_distance_matches_stepping(input in aoc_gen::day8::input(0..=30), extra in 0..=2u64) {
+        let maps = Day8::DEFAULT.parse(&input).unwrap();
+        let limit = (maps.network.len() * maps.instructions.len()) as u64;
+        let mut node = "AAA";
+        for (steps, &direction) in (0..=limit * (1 + extra)).zip(maps.instructions.iter().cycle()) {
+            prop_assert_eq!(walk("AAA", &maps.network, &maps.instructions, steps), Ok(node.to_string()));
+            node = &maps.network[node][direction];
+        }
+    }
+}
+
+#[test]
+fn huge_walks_finish_on_the_right_node() {
+    let maps = Day8::DEFAULT.parse(include_str!("../test2")).unwrap();
+    let walk_from = |node, steps| walk(node, &maps.network, &maps.instructions, steps).unwrap();
+    // OOA's ghost alternates between OOB and OOZ, TTA's goes round TTB, TTC and TTZ
+    assert_eq!(walk_from("OOA", u64::MAX), "OOB");
+    assert_eq!(walk_from("OOA", u64::MAX - 1), "OOZ");
+    assert_eq!(walk_from("TTA", u64::MAX), "TTZ");
+    assert_eq!(walk_from("TTA", u64::MAX - 1), "TTC");
+}
+
+#[test]
+fn ghosts_that_dont_loop_evenly_are_an_error() {
+    // They're first on Z nodes at steps 1 and 2, but only both at once at step 4
+    let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11C, 11C)\n11C = (11Z, 11Z)\n\
+                 22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22Z, 22Z)\n";
+    let maps = Day8::DEFAULT.parse(input).unwrap();
+    assert_eq!(
+        Day8::DEFAULT.part2(&maps).unwrap_err().to_string(),
+        "the ghost from `11A` doesn't come back to `11Z` every 1 steps, so the ghosts can't be lined up"
+    );
+}
@@ -0,0 +1,189 @@
+//! Day 9: Mirage Maintenance. Extrapolates OASIS sequences forwards and
+//! backwards.
+
+use aoc_common::parse::{self, Line};
+use aoc_common::stream;
+use aoc_common::{
+    with_width, Accumulator, BigInt, ParseResult, Solution, SolveResult, Step, StreamResult, Width,
+};
+use std::io::BufRead;
+
+fn all_equal<N: Accumulator>(seq: &[N]) -> bool {
+    seq.windows(2).all(|pair| pair[0] == pair[1])
+}
+
+/// Differences between each pair of neighbouring values, or `None` if one
+/// overflows.
+pub fn get_deltas<N: Accumulator>(seq: &[N]) -> Option<Vec<N>> {
+    seq.windows(2)
+        .map(|pair| pair[1].checked_sub(&pair[0]))
+        .collect()
+}
+
+/// Extrapolates the value after the end of `seq` from its differences. `None` if
+/// `seq` is empty or a value overflows.
+pub fn get_next_value<N: Accumulator>(seq: &[N]) -> Option<N> {
+    let last = seq.last()?;
+    if all_equal(seq) {
+        return Some(last.clone());
+    }
+    let deltas = get_deltas(seq)?;
+    last.checked_add(&get_next_value(&deltas)?)
+}
+
+/// Extrapolates the value before the start of `seq` from its differences. `None`
+/// if `seq` is empty or a value overflows.
+pub fn get_first_value<N: Accumulator>(seq: &[N]) -> Option<N> {
+    let first = seq.first()?;
+    if all_equal(seq) {
+        return Some(first.clone());
+    }
+    let deltas = get_deltas(seq)?;
+    first.checked_sub(&get_first_value(&deltas)?)
+}
+
+/// `seq` and each row of differences under it, down to the first row whose
+/// values are all equal. `None` if `seq` is empty or a difference overflows.
+pub fn pyramid<N: Accumulator>(seq: &[N]) -> Option<Vec<Vec<N>>> {
+    if seq.is_empty() {
+        return None;
+    }
+    let mut rows = vec![seq.to_vec()];
+    while !all_equal(&rows[rows.len() - 1]) {
+        rows.push(get_deltas(&rows[rows.len() - 1])?);
+    }
+    Some(rows)
+}
+
+/// Rows of `seq`'s pyramid, each with its extrapolated value: after the row for
+/// part 1, before it for part 2. Worked out in a `BigInt` so nothing overflows.
+fn explain_pyramid(line: usize, seq: &[i32], part: u8) -> Vec<Step> {
+    let seq: Vec<BigInt> = seq.iter().map(|&value| BigInt::from(value)).collect();
+    let Some(rows) = pyramid(&seq) else {
+        return Vec::new();
+    };
+    let mut extrapolated = BigInt::ZERO;
+    let mut steps = Vec::new();
+    for (depth, row) in rows.iter().enumerate().rev() {
+        extrapolated = if depth == rows.len() - 1 {
+            row[0].clone()
+        } else if part == 1 {
+            &row[row.len() - 1] + &extrapolated
+        } else {
+            &row[0] - &extrapolated
+        };
+
+        let values: Vec<String> = row.iter().map(BigInt::to_string).collect();
+        let text = match part {
+            1 => format!(
+                "{}{} [{}]",
+                "  ".repeat(depth),
+                values.join(" "),
+                extrapolated
+            ),
+            _ => format!(
+                "{}[{}] {}",
+                "  ".repeat(depth),
+                extrapolated,
+                values.join(" ")
+            ),
+        };
+        steps.push(Step::new(text).on_line(line));
+    }
+    steps.reverse();
+    steps
+}
+
+/// The value `extrapolate` finds for `seq`, from line `line`.
+fn extrapolated<N: Accumulator>(
+    seq: &[i32],
+    extrapolate: fn(&[N]) -> Option<N>,
+    line: usize,
+) -> SolveResult<N> {
+    seq.iter()
+        .map(|&value| N::of(value))
+        .collect::<Option<Vec<N>>>()
+        .and_then(|seq| extrapolate(&seq))
+        .ok_or_else(|| N::overflow().on_line(line))
+}
+
+/// Sum of the values `extrapolate` finds for each sequence.
+pub fn sum_values<N: Accumulator>(
+    sequences: &[Vec<i32>],
+    extrapolate: fn(&[N]) -> Option<N>,
+) -> SolveResult<N> {
+    sequences
+        .iter()
+        .enumerate()
+        .try_fold(N::zero(), |acc, (i, seq)| {
+            acc.checked_add(&extrapolated(seq, extrapolate, i + 1)?)
+                .ok_or_else(|| N::overflow().on_line(i + 1))
+        })
+}
+
+/// Both parts' sums from one pass over `reader`, a sequence at a time.
+fn stream_sums<N: Accumulator>(reader: &mut dyn BufRead) -> StreamResult<N> {
+    let mut sums = [Ok(N::zero()), Ok(N::zero())];
+    stream::for_each_line(reader, |line| {
+        let seq = parse_sequence(line)?;
+        let [next, first] = &mut sums;
+        stream::add(next, line.number, || {
+            extrapolated(&seq, get_next_value, line.number)
+        });
+        stream::add(first, line.number, || {
+            extrapolated(&seq, get_first_value, line.number)
+        });
+        Ok(())
+    })?;
+    Ok(sums)
+}
+
+/// Parses a line of space separated values.
+pub fn parse_sequence(line: Line) -> ParseResult<Vec<i32>> {
+    let seq: Vec<i32> = line.numbers(line.text)?;
+    if seq.is_empty() {
+        return Err(line.error_after(line.text, "expected a sequence"));
+    }
+    Ok(seq)
+}
+
+/// Parses each line into a sequence.
+#[derive(Clone)]
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i32>>;
+    type Answer = i32;
+
+    fn parse(&self, input: &str) -> ParseResult<Self::Input> {
+        parse::lines(input).map(parse_sequence).collect()
+    }
+
+    fn part1(&self, sequences: &Self::Input) -> SolveResult<i32> {
+        sum_values(sequences, get_next_value)
+    }
+
+    fn part2(&self, sequences: &Self::Input) -> SolveResult<i32> {
+        sum_values(sequences, get_first_value)
+    }
+
+    fn part_with(&self, sequences: &Self::Input, part: u8, width: Width) -> SolveResult<String> {
+        with_width!(width, signed i32, |N| match part {
+            1 => sum_values::<N>(sequences, get_next_value),
+            _ => sum_values::<N>(sequences, get_first_value),
+        })
+    }
+
+    fn stream(&self, reader: &mut dyn BufRead, width: Width) -> Option<StreamResult> {
+        Some(with_width!(width, signed i32, |N| stream_sums::<N>(reader)))
+    }
+
+    /// The difference pyramid under each sequence.
+    fn explain(&self, sequences: &Self::Input, part: u8) -> Vec<Step> {
+        sequences
+            .iter()
+            .enumerate()
+            .flat_map(|(i, seq)| explain_pyramid(i + 1, seq, part))
+            .collect()
+    }
+}
@@ -0,0 +1,35 @@
+use aoc_common::Solution;
+use aoc_gen::day9::evaluate;
+use day9::{get_first_value, get_next_value, Day9};
+use proptest::prelude::*;
+
+fn to_i32(values: &[i64]) -> Vec<i32> {
+    values.iter().map(|&value| value as i32).collect()
+}
+
+proptest! {
+    #[test]
+    fn first_value_is_next_value_reversed((_, values) in aoc_gen::day9::sequence()) {
+        let seq = to_i32(&values);
+        let reversed: Vec<i32> = seq.iter().rev().copied().collect();
+        prop_assert_eq!(get_first_value(&seq), get_next_value(&reversed));
+    }
+
+    #[test]
+    fn extrapolates_the_polynomial((coefficients, values) in aoc_gen::day9::sequence()) {
+        let seq = to_i32(&values);
+        let len = values.len() as i64;
+        prop_assert_eq!(get_next_value(&seq).map(i64::from), Some(evaluate(&coefficients, len)));
+        prop_assert_eq!(get_first_value(&seq).map(i64::from), Some(evaluate(&coefficients, -1)));
+    }
+
+    #[test]
+    fn part2_is_part1_of_the_reversed_lines(input in aoc_gen::day9::input(1..=20)) {
+        let reversed: String = input
+            .lines()
+            .map(|line| line.split(' ').rev().collect::<Vec<_>>().join(" ") + "\n")
+            .collect();
+        let sequences = Day9.parse(&input).unwrap();
+        prop_assert_eq!(Day9.part2(&sequences), Day9.part1(&Day9.parse(&reversed).unwrap()));
+    }
+}
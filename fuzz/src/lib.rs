@@ -0,0 +1,22 @@
+//! Fuzz targets for each day's parse and solve path, run with
+//! `cargo +nightly fuzz run dayN` from this directory. Every input has to come
+//! back as an answer or an error; a panic or a hang is a bug. Inputs that caught
+//! one go in `regressions/dayN/`, which `cargo test -p aoc` replays.
+
+use aoc_common::DynSolution;
+
+/// Parses `data` and runs both parts on it, rendering any parse error too.
+pub fn run(solution: &dyn DynSolution, data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    match solution.parse(input) {
+        Ok(parsed) => {
+            let _ = parsed.part1();
+            let _ = parsed.part2();
+        }
+        Err(err) => {
+            let _ = err.render(input);
+        }
+    }
+}
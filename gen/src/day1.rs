@@ -0,0 +1,39 @@
+use proptest::prelude::*;
+use proptest::sample::select;
+use proptest::test_runner::TestRunner;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+
+pub const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn token() -> impl Strategy<Value = String> {
+    prop_oneof![
+        (1..=9u32).prop_map(|digit| digit.to_string()),
+        select(&WORDS[..]).prop_map(String::from),
+        prop::collection::vec(select(('a'..='z').collect::<Vec<_>>()), 1..=4)
+            .prop_map(|letters| letters.into_iter().collect()),
+    ]
+}
+
+/// A calibration line: letters, digits and spelled out digits, with at least one
+/// numeral so both parts have an answer.
+pub fn line() -> impl Strategy<Value = String> {
+    let tokens = || prop::collection::vec(token(), 0..=4);
+    (tokens(), 1..=9u32, tokens())
+        .prop_map(|(before, digit, after)| before.concat() + &digit.to_string() + &after.concat())
+}
+
+pub fn input(size: RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    prop::collection::vec(line(), size).prop_map(super::lines)
+}
+
+/// `size` calibration lines.
+pub fn write(size: usize, runner: &mut TestRunner, out: &mut dyn Write) -> io::Result<()> {
+    let line = line();
+    for _ in 0..size {
+        writeln!(out, "{}", super::sample(&line, runner))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use proptest::prelude::*;
+use proptest::sample::subsequence;
+use proptest::test_runner::TestRunner;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+
+/// A round like `3 blue, 4 red`, each colour at most once in any order.
+pub fn round() -> impl Strategy<Value = String> {
+    let colours = subsequence(vec!["red", "green", "blue"], 1..=3).prop_shuffle();
+    (colours, prop::collection::vec(1..=20u32, 3)).prop_map(|(colours, counts)| {
+        colours
+            .iter()
+            .zip(counts)
+            .map(|(colour, count)| format!("{} {}", count, colour))
+            .collect::<Vec<_>>()
+            .join(", ")
+    })
+}
+
+/// The rounds of one game.
+pub fn game() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec(round(), 1..=6)
+}
+
+fn game_line(id: usize, rounds: &[String]) -> String {
+    format!("Game {}: {}", id, rounds.join("; "))
+}
+
+/// Games numbered from 1, each with a few rounds.
+pub fn input(size: RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    prop::collection::vec(game(), size).prop_map(|games| {
+        super::lines(
+            games
+                .iter()
+                .enumerate()
+                .map(|(i, rounds)| game_line(i + 1, rounds)),
+        )
+    })
+}
+
+/// `size` games.
+pub fn write(size: usize, runner: &mut TestRunner, out: &mut dyn Write) -> io::Result<()> {
+    let game = game();
+    for id in 1..=size {
+        writeln!(out, "{}", game_line(id, &super::sample(&game, runner)))?;
+    }
+    Ok(())
+}
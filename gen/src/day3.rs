@@ -0,0 +1,63 @@
+use proptest::prelude::*;
+use proptest::sample::select;
+use proptest::test_runner::TestRunner;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+
+pub const SYMBOLS: &str = "*#+$/@=%-&";
+
+#[derive(Debug, Clone)]
+enum Token {
+    Number(u32),
+    Dots(usize),
+    Symbol(char),
+}
+
+fn token() -> impl Strategy<Value = Token> {
+    prop_oneof![
+        (1..1000u32).prop_map(Token::Number),
+        (1..=4usize).prop_map(Token::Dots),
+        select(SYMBOLS.chars().collect::<Vec<_>>()).prop_map(Token::Symbol),
+    ]
+}
+
+/// One schematic row exactly `width` cells wide. Numbers have at most three
+/// digits, as in the real inputs.
+pub fn row(width: usize) -> impl Strategy<Value = String> {
+    prop::collection::vec(token(), 1..=width.max(1)).prop_map(move |tokens| {
+        let mut row = String::new();
+        let mut after_number = false;
+        for token in tokens {
+            match token {
+                Token::Number(value) => {
+                    // Keep neighbouring numbers from running together
+                    if after_number {
+                        row.push('.');
+                    }
+                    row += &value.to_string();
+                }
+                Token::Dots(count) => row += &".".repeat(count),
+                Token::Symbol(symbol) => row.push(symbol),
+            }
+            after_number = matches!(token, Token::Number(_));
+        }
+        let mut row: String = row.chars().take(width).collect();
+        row += &".".repeat(width - row.len());
+        row
+    })
+}
+
+/// A square schematic with sides of `size` cells.
+pub fn input(size: RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    size.prop_flat_map(|side| prop::collection::vec(row(side), side.max(1)))
+        .prop_map(super::lines)
+}
+
+/// A `size` by `size` schematic, drawn a row at a time.
+pub fn write(size: usize, runner: &mut TestRunner, out: &mut dyn Write) -> io::Result<()> {
+    let row = row(size);
+    for _ in 0..size.max(1) {
+        writeln!(out, "{}", super::sample(&row, runner))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestRunner;
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+
+/// Five winning and eight played numbers.
+pub fn card() -> impl Strategy<Value = (BTreeSet<u32>, BTreeSet<u32>)> {
+    (
+        prop::collection::btree_set(1..100u32, 5),
+        prop::collection::btree_set(1..100u32, 8),
+    )
+}
+
+fn numbers(numbers: &BTreeSet<u32>) -> String {
+    numbers
+        .iter()
+        .map(|number| format!("{:>2}", number))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Card `i` of `count`, dropping played numbers that would win copies of cards past
+/// the end of the pile.
+fn card_line(i: usize, count: usize, winning: &BTreeSet<u32>, mut played: BTreeSet<u32>) -> String {
+    let mut allowed = count - 1 - i;
+    played.retain(|number| {
+        if !winning.contains(number) {
+            true
+        } else if allowed > 0 {
+            allowed -= 1;
+            true
+        } else {
+            false
+        }
+    });
+    format!(
+        "Card {:>3}: {} | {}",
+        i + 1,
+        numbers(winning),
+        numbers(&played)
+    )
+}
+
+/// Scratchcards where no card wins copies of cards past the end of the pile.
+pub fn input(size: RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    prop::collection::vec(card(), size).prop_map(|cards| {
+        let count = cards.len();
+        super::lines(
+            cards
+                .into_iter()
+                .enumerate()
+                .map(|(i, (winning, played))| card_line(i, count, &winning, played)),
+        )
+    })
+}
+
+/// `size` scratchcards.
+pub fn write(size: usize, runner: &mut TestRunner, out: &mut dyn Write) -> io::Result<()> {
+    let card = card();
+    for i in 0..size {
+        let (winning, played) = super::sample(&card, runner);
+        writeln!(out, "{}", card_line(i, size, &winning, played))?;
+    }
+    Ok(())
+}
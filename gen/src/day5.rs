@@ -0,0 +1,100 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestRunner;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+
+pub const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// The mapping lines of one map, which shuffles some neighbouring blocks of values
+/// around so no two values land on the same one. No block has a range over
+/// `max_range`.
+pub fn map(size: RangeInclusive<usize>, max_range: u64) -> impl Strategy<Value = Vec<String>> {
+    let blocks = prop::collection::vec(0..=max_range, size);
+    (0..=max_range, blocks)
+        .prop_flat_map(|(start, blocks)| {
+            let order: Vec<usize> = (0..blocks.len()).collect();
+            (Just(start), Just(blocks), Just(order).prop_shuffle())
+        })
+        .prop_map(|(start, blocks, order)| {
+            // Each block covers `range + 1` values from its start
+            let mut src = start;
+            let srcs: Vec<u64> = blocks
+                .iter()
+                .map(|&range| {
+                    let block = src;
+                    src += range + 1;
+                    block
+                })
+                .collect();
+
+            let mut dest = start;
+            let mut mappings = vec![String::new(); blocks.len()];
+            for i in order {
+                let range = blocks[i];
+                mappings[i] = format!("{} {} {}", dest, srcs[i], range);
+                dest += range + 1;
+            }
+            mappings
+        })
+}
+
+/// A few seed ranges, as the first line of an almanac.
+fn seeds(max_range: u64) -> impl Strategy<Value = String> {
+    prop::collection::vec((0..max_range * 4, 1..max_range), 1..=4).prop_map(|seeds| {
+        let seeds: Vec<String> = seeds
+            .iter()
+            .map(|(start, len)| format!("{} {}", start, len))
+            .collect();
+        format!("seeds: {}", seeds.join(" "))
+    })
+}
+
+/// The line naming map `i`, from one category to the next.
+fn map_header(i: usize) -> String {
+    format!("{}-to-{} map:", CATEGORIES[i], CATEGORIES[i + 1])
+}
+
+/// An almanac with a few seed ranges and the seven maps from seed to location,
+/// each with `size` mappings.
+pub fn almanac(size: RangeInclusive<usize>, max_range: u64) -> impl Strategy<Value = String> {
+    let maps = prop::collection::vec(map(size, max_range), CATEGORIES.len() - 1);
+    (seeds(max_range), maps).prop_map(|(seeds, maps)| {
+        let mut lines = vec![seeds];
+        for (i, mappings) in maps.into_iter().enumerate() {
+            lines.push(String::new());
+            lines.push(map_header(i));
+            lines.extend(mappings);
+        }
+        super::lines(lines)
+    })
+}
+
+/// Small almanacs, with few enough seeds to try every one.
+pub fn input(size: RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    almanac(size, 30)
+}
+
+/// An almanac with `size` mappings per map, spread over as much of the 64-bit range
+/// as they can be without any value overflowing. Each map shuffles its blocks, so
+/// it's drawn whole, but only one is held at a time.
+pub fn write(size: usize, runner: &mut TestRunner, out: &mut dyn Write) -> io::Result<()> {
+    let max_range = u64::MAX / 8 / (size as u64 + 1);
+    writeln!(out, "{}", super::sample(&seeds(max_range), runner))?;
+    let map = map(size..=size, max_range);
+    for i in 0..CATEGORIES.len() - 1 {
+        writeln!(out, "\n{}", map_header(i))?;
+        for mapping in super::sample(&map, runner) {
+            writeln!(out, "{}", mapping)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestRunner;
+use std::io::{self, Write};
+use std::ops::{Range, RangeInclusive};
+
+/// A race time and a record that can be beaten in it.
+pub fn race() -> impl Strategy<Value = (u64, u64)> {
+    race_in(2..100)
+}
+
+/// Like [`race`], with the time picked from `times`. Records stay within a `u64`
+/// for times up to about 8e9.
+pub fn race_in(times: Range<u64>) -> impl Strategy<Value = (u64, u64)> {
+    times.prop_flat_map(|time| {
+        let best = (time / 2) * (time - time / 2);
+        (Just(time), 0..best)
+    })
+}
+
+pub fn input(size: RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    prop::collection::vec(race(), size).prop_map(|races| {
+        let row = |values: Vec<u64>| {
+            values
+                .iter()
+                .map(|value| format!("{:>5}", value))
+                .collect::<String>()
+        };
+        let time = row(races.iter().map(|race| race.0).collect());
+        let dist = row(races.iter().map(|race| race.1).collect());
+        super::lines([format!("Time:    {}", time), format!("Distance:{}", dist)])
+    })
+}
+
+/// `size` races. The distances are a row of their own after the times, so the
+/// races are drawn twice over from the same starting point rather than kept.
+pub fn write(size: usize, runner: &mut TestRunner, out: &mut dyn Write) -> io::Result<()> {
+    let race = race();
+    let mut again = runner.clone();
+    write!(out, "Time:    ")?;
+    for _ in 0..size {
+        write!(out, "{:>5}", super::sample(&race, runner).0)?;
+    }
+    write!(out, "\nDistance:")?;
+    for _ in 0..size {
+        write!(out, "{:>5}", super::sample(&race, &mut again).1)?;
+    }
+    writeln!(out)
+}
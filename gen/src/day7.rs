@@ -0,0 +1,29 @@
+use proptest::prelude::*;
+use proptest::sample::select;
+use proptest::test_runner::TestRunner;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+
+/// Five cards from `AKQJT98765432`.
+pub fn hand() -> impl Strategy<Value = String> {
+    let cards: Vec<char> = "AKQJT98765432".chars().collect();
+    prop::collection::vec(select(cards), 5).prop_map(|cards| cards.into_iter().collect())
+}
+
+/// A hand and its bid, as one line.
+pub fn round() -> impl Strategy<Value = String> {
+    (hand(), 1..1000u32).prop_map(|(hand, bid)| format!("{} {}", hand, bid))
+}
+
+pub fn input(size: RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    prop::collection::vec(round(), size).prop_map(super::lines)
+}
+
+/// `size` hands.
+pub fn write(size: usize, runner: &mut TestRunner, out: &mut dyn Write) -> io::Result<()> {
+    let round = round();
+    for _ in 0..size {
+        writeln!(out, "{}", super::sample(&round, runner))?;
+    }
+    Ok(())
+}
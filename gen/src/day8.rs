@@ -0,0 +1,90 @@
+use proptest::prelude::*;
+use proptest::sample::{select, Index};
+use proptest::test_runner::TestRunner;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+
+/// A three letter node name for each index that neither starts nor ends the walk,
+/// so `AAA` and `ZZZ` stay the only start and end.
+pub fn node_name(index: usize) -> String {
+    const LETTERS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+    let letter = |i: usize| LETTERS[i % LETTERS.len()] as char;
+    let base = LETTERS.len();
+    [
+        letter(index / (base * base)),
+        letter(index / base),
+        letter(index),
+    ]
+    .iter()
+    .collect()
+}
+
+/// Names past the 24³ three letter ones get longer, which the parser doesn't mind.
+fn name(i: usize, last: usize) -> String {
+    const THREE_LETTERS: usize = 24 * 24 * 24;
+    match i {
+        0 => "AAA".to_string(),
+        i if i == last => "ZZZ".to_string(),
+        i if i - 1 < THREE_LETTERS => node_name(i - 1),
+        i => format!("{}{}", node_name((i - 1) / THREE_LETTERS), node_name(i - 1)),
+    }
+}
+
+pub fn instructions() -> impl Strategy<Value = String> {
+    prop::collection::vec(select(vec!['L', 'R']), 1..=10)
+        .prop_map(|instructions| instructions.into_iter().collect())
+}
+
+/// Where node `i` leads: one side to the next node, the other to some node further
+/// on, swapped over if `swap`. `ZZZ`, node `last`, leads back to itself.
+fn node_line(i: usize, last: usize, (jump, swap): (Index, bool)) -> String {
+    let (left, right) = if i == last {
+        (last, last)
+    } else {
+        let next = i + 1;
+        let far = next + jump.index(last - i);
+        if swap {
+            (far, next)
+        } else {
+            (next, far)
+        }
+    };
+    format!(
+        "{} = ({}, {})",
+        name(i, last),
+        name(left, last),
+        name(right, last)
+    )
+}
+
+/// Instructions and a network where every node on the way from `AAA` leads
+/// strictly closer to `ZZZ`, so the walk always gets there. `size` is how many
+/// nodes lie between them.
+pub fn input(size: RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    let network = size.prop_flat_map(|between| {
+        let nodes = between + 2;
+        (
+            prop::collection::vec((any::<Index>(), any::<bool>()), nodes),
+            Just((0..nodes).collect::<Vec<_>>()).prop_shuffle(),
+        )
+    });
+
+    (instructions(), network).prop_map(|(instructions, (edges, order))| {
+        let last = edges.len() - 1;
+        let mut lines = vec![instructions, String::new()];
+        lines.extend(order.into_iter().map(|i| node_line(i, last, edges[i])));
+        super::lines(lines)
+    })
+}
+
+/// A network with `size` nodes between `AAA` and `ZZZ`, written in order rather
+/// than shuffled.
+pub fn write(size: usize, runner: &mut TestRunner, out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "{}\n", super::sample(&instructions(), runner))?;
+    let last = size + 1;
+    let edge = (any::<Index>(), any::<bool>());
+    for i in 0..=last {
+        writeln!(out, "{}", node_line(i, last, super::sample(&edge, runner)))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use proptest::prelude::*;
+use proptest::test_runner::TestRunner;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+
+/// Coefficients of a polynomial of degree at most three, lowest power first.
+pub fn polynomial() -> impl Strategy<Value = Vec<i64>> {
+    prop::collection::vec(-5..=5i64, 1..=4)
+}
+
+pub fn evaluate(coefficients: &[i64], x: i64) -> i64 {
+    coefficients.iter().rev().fold(0, |acc, &c| acc * x + c)
+}
+
+/// A sequence of a polynomial's values at `0, 1, 2, ...`, with its coefficients.
+pub fn sequence() -> impl Strategy<Value = (Vec<i64>, Vec<i64>)> {
+    (polynomial(), 5..=15i64).prop_map(|(coefficients, len)| {
+        let values = (0..len).map(|x| evaluate(&coefficients, x)).collect();
+        (coefficients, values)
+    })
+}
+
+fn sequence_line(values: &[i64]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn input(size: RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    prop::collection::vec(sequence(), size).prop_map(|sequences| {
+        super::lines(sequences.iter().map(|(_, values)| sequence_line(values)))
+    })
+}
+
+/// `size` sequences.
+pub fn write(size: usize, runner: &mut TestRunner, out: &mut dyn Write) -> io::Result<()> {
+    let sequence = sequence();
+    for _ in 0..size {
+        let (_, values) = super::sample(&sequence, runner);
+        writeln!(out, "{}", sequence_line(&values))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//! Proptest strategies that produce valid puzzle inputs for each day, as the text
+//! the day's parser reads. `size` is how many lines, games, cards and so on to
+//! generate, or the side length for day 3's grid.
+//!
+//! Each day also has a `write` that streams a single input of exactly `size` out
+//! a piece at a time, so `aoc gen` can make inputs far bigger than the strategies
+//! would fit in memory. The pieces are lines, except for day 5, which writes one
+//! map at a time since each is shuffled as a whole.
+
+use proptest::strategy::{Strategy, ValueTree};
+use proptest::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
+use std::io::{self, Write};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// Joins lines with a newline after each one, as the input files have.
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+/// A runner that always draws the same values for the same seed.
+pub fn runner(seed: u64) -> TestRunner {
+    let mut bytes = [0; 32];
+    bytes[..8].copy_from_slice(&seed.to_le_bytes());
+    let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &bytes);
+    TestRunner::new_with_rng(Config::default(), rng)
+}
+
+/// Draws one value from `strategy`.
+pub fn sample<S: Strategy>(strategy: &S, runner: &mut TestRunner) -> S::Value {
+    // Only filters reject values, and none of these strategies filter
+    strategy
+        .new_tree(runner)
+        .unwrap_or_else(|reason| panic!("strategy rejected every value: {}", reason))
+        .current()
+}
+
+/// Writes a generated input for `day` to `out`.
+pub fn write(day: u8, size: usize, seed: u64, out: &mut dyn Write) -> io::Result<()> {
+    let runner = &mut runner(seed);
+    match day {
+        1 => day1::write(size, runner, out),
+        2 => day2::write(size, runner, out),
+        3 => day3::write(size, runner, out),
+        4 => day4::write(size, runner, out),
+        5 => day5::write(size, runner, out),
+        6 => day6::write(size, runner, out),
+        7 => day7::write(size, runner, out),
+        8 => day8::write(size, runner, out),
+        9 => day9::write(size, runner, out),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("no generator for day {}", day),
+        )),
+    }
+}
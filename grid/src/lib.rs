@@ -0,0 +1,229 @@
+use aoc_common::{ParseError, ParseResult};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A cell position, counted from the top left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+
+    /// The position `rows` down and `cols` right of this one, if that doesn't go
+    /// past the top or left edge.
+    pub fn offset(self, rows: isize, cols: isize) -> Option<Pos> {
+        Some(Pos {
+            row: self.row.checked_add_signed(rows)?,
+            col: self.col.checked_add_signed(cols)?,
+        })
+    }
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a character map, one row per line. Every row must be the same length.
+    pub fn parse(text: &str) -> ParseResult<Self> {
+        Grid::parse_with(text, Ok)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, turning each character into a cell with `cell`.
+    /// Returning `Err(message)` from `cell` reports that character as invalid.
+    pub fn parse_with<F>(text: &str, mut cell: F) -> ParseResult<Self>
+    where
+        F: FnMut(char) -> Result<T, &'static str>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (row, line) in text.lines().enumerate() {
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                let value = cell(c).map_err(|message| {
+                    ParseError::at(row + 1, line, &line[i..i + c.len_utf8()], message)
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let message = format!("expected {} columns, found {}", width, row_width);
+                    return Err(ParseError::at(row + 1, line, line, message));
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.row * self.width + pos.col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.row * self.width + pos.col])
+        } else {
+            None
+        }
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        pos: Pos,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&(rows, cols)| pos.offset(rows, cols))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// The up to four cells above, below, left and right of `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &ORTHOGONAL)
+    }
+
+    /// The up to eight cells surrounding `pos`, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &SURROUNDING)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Pos::new(i / width, i % width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on a zero chunk size, which an empty grid would give it
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Cells in the rectangle from `top_left` to `bottom_right` inclusive, clipped
+    /// to the grid.
+    pub fn region(&self, top_left: Pos, bottom_right: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        let rows = top_left.row..=bottom_right.row.min(self.height.saturating_sub(1));
+        let cols = top_left.col..=bottom_right.col.min(self.width.saturating_sub(1));
+        rows.flat_map(move |row| cols.clone().map(move |col| Pos::new(row, col)))
+            .filter(|&pos| self.contains(pos))
+            .map(|pos| (pos, &self[pos]))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{:?} out of bounds for {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} out of bounds for {}x{} grid", pos, width, height))
+    }
+}
+
+/// Prints one line per row with the cells run together, so a parsed character map
+/// prints back out as it was read.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use aoc_grid::{Grid, Pos};
+
+const MAP: &str = "\
+467..
+...*.
+..35.
+";
+
+fn sorted(positions: impl Iterator<Item = Pos>) -> Vec<Pos> {
+    let mut positions: Vec<Pos> = positions.collect();
+    positions.sort();
+    positions
+}
+
+#[test]
+fn parse_and_index() {
+    let grid = Grid::parse(MAP).unwrap();
+    assert_eq!((grid.width(), grid.height()), (5, 3));
+    assert_eq!(grid[Pos::new(1, 3)], '*');
+    assert_eq!(grid.get(Pos::new(3, 0)), None);
+    assert_eq!(grid.get(Pos::new(0, 5)), None);
+}
+
+#[test]
+fn parse_rejects_ragged_rows() {
+    let err = Grid::parse("...\n....\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+}
+
+#[test]
+fn parse_with_reports_bad_cells() {
+    let err = Grid::parse_with("..\n.#\n", |c| match c {
+        '.' => Ok(false),
+        _ => Err("unexpected cell"),
+    })
+    .unwrap_err();
+    assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "#"));
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds_panics() {
+    let grid = Grid::parse(MAP).unwrap();
+    let _ = grid[Pos::new(0, 5)];
+}
+
+#[test]
+fn neighbours_stay_in_bounds() {
+    let grid = Grid::parse(MAP).unwrap();
+
+    assert_eq!(
+        sorted(grid.neighbours4(Pos::new(0, 0))),
+        vec![Pos::new(0, 1), Pos::new(1, 0)]
+    );
+    assert_eq!(
+        sorted(grid.neighbours8(Pos::new(0, 0))),
+        vec![Pos::new(0, 1), Pos::new(1, 0), Pos::new(1, 1)]
+    );
+    assert_eq!(grid.neighbours4(Pos::new(1, 2)).count(), 4);
+    assert_eq!(grid.neighbours8(Pos::new(1, 2)).count(), 8);
+    assert_eq!(grid.neighbours8(Pos::new(2, 4)).count(), 3);
+}
+
+#[test]
+fn rows_columns_and_regions() {
+    let grid = Grid::parse(MAP).unwrap();
+
+    let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+    assert_eq!(rows, vec!["467..", "...*.", "..35."]);
+    assert_eq!(grid.column(2).collect::<String>(), "7.3");
+
+    let region: String = grid
+        .region(Pos::new(1, 2), Pos::new(5, 9))
+        .map(|(_, &c)| c)
+        .collect();
+    assert_eq!(region, ".*.35.");
+}
+
+#[test]
+fn display_round_trips() {
+    let grid = Grid::parse(MAP).unwrap();
+    assert_eq!(grid.to_string(), MAP);
+    assert_eq!(
+        grid.map(|&c| c == '*')
+            .map(|&b| if b { '#' } else { '.' })
+            .to_string(),
+        ".....\n...#.\n.....\n"
+    );
+}